@@ -1,5 +1,5 @@
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
-use justext::{get_all_stoplists, get_stoplist, justext, Config};
+use justext::{get_all_stoplists, get_stoplist, get_stoplist_set, justext, Config};
 use std::hint::black_box;
 
 // ---------------------------------------------------------------------------
@@ -102,11 +102,16 @@ fn bench_stoplists(c: &mut Criterion) {
 
     let mut group = c.benchmark_group("stoplists");
 
-    // Per-call: allocates and parses a new HashSet each time.
+    // Per-call: copies the compiled-in set into a new HashSet each time.
     group.bench_function("get_stoplist_per_call", |b| {
         b.iter(|| get_stoplist(black_box("English")).unwrap())
     });
 
+    // Zero-allocation view of the compiled-in set.
+    group.bench_function("get_stoplist_set", |b| {
+        b.iter(|| get_stoplist_set(black_box("English")).unwrap())
+    });
+
     // Cached: LazyLock, pointer-sized return after first call.
     group.bench_function("get_all_stoplists_cached", |b| b.iter(get_all_stoplists));
 
@@ -0,0 +1,60 @@
+// Compiles the embedded stoplists (src/stoplists/*.txt) into static `phf::Set`s, so
+// lookups need no runtime parsing. Generates $OUT_DIR/stoplists.rs, included by
+// src/stoplists/mod.rs.
+
+use std::collections::BTreeSet;
+use std::fmt::Write as _;
+use std::path::Path;
+use std::{env, fs};
+
+fn main() {
+    let dir = Path::new("src/stoplists");
+    println!("cargo:rerun-if-changed={}", dir.display());
+
+    let mut languages: Vec<String> = fs::read_dir(dir)
+        .expect("read src/stoplists")
+        .map(|entry| entry.expect("read stoplist entry").path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .map(|path| path.file_stem().unwrap().to_string_lossy().into_owned())
+        .collect();
+    languages.sort();
+
+    let mut out = String::new();
+    for language in &languages {
+        let contents =
+            fs::read_to_string(dir.join(format!("{language}.txt"))).expect("read stoplist");
+        // Same rules as runtime registration: trimmed, lowercased, blank lines skipped.
+        // phf rejects duplicate keys, so dedupe first.
+        let words: BTreeSet<String> = contents
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(str::to_lowercase)
+            .collect();
+        let mut set = phf_codegen::Set::new();
+        for word in &words {
+            set.entry(word.as_str());
+        }
+        writeln!(
+            out,
+            "static {}: phf::Set<&'static str> = {};",
+            static_name(language),
+            set.build()
+        )
+        .unwrap();
+    }
+
+    out.push_str("\n/// All embedded stoplists: (language_name, words), sorted by name.\n");
+    out.push_str("static STOPLISTS: &[(&str, &phf::Set<&'static str>)] = &[\n");
+    for language in &languages {
+        writeln!(out, "    ({language:?}, &{}),", static_name(language)).unwrap();
+    }
+    out.push_str("];\n");
+
+    let dest = Path::new(&env::var("OUT_DIR").unwrap()).join("stoplists.rs");
+    fs::write(dest, out).expect("write stoplists.rs");
+}
+
+fn static_name(language: &str) -> String {
+    format!("STOPLIST_{}", language.to_uppercase())
+}
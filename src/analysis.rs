@@ -0,0 +1,205 @@
+// Post-classification helpers that look at paragraphs as a whole document.
+
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+
+use crate::paragraph::{ClassType, Paragraph};
+use crate::{justext, Config};
+
+/// Group paragraphs by their ancestor container at depth `level`.
+///
+/// The key is the paragraph's XPath truncated to its first `level` steps (with any
+/// `#n` de-duplication suffix removed), e.g. `"/html[1]/body[1]/div[2]"` for
+/// `level = 3`. The XPath is used rather than `dom_path` because `dom_path` carries
+/// no ordinals, so sibling `<div>`s would otherwise collapse into one group.
+/// Paragraphs shallower than `level` are keyed by their full path.
+///
+/// Groups appear in order of their first paragraph, and paragraphs keep document
+/// order within a group. Filter to good paragraphs first if boilerplate shouldn't be
+/// grouped.
+pub fn group_by_container(
+    paragraphs: &[Paragraph],
+    level: usize,
+) -> Vec<(String, Vec<&Paragraph>)> {
+    let mut groups: Vec<(String, Vec<&Paragraph>)> = Vec::new();
+    for paragraph in paragraphs {
+        let key = container_path(&paragraph.xpath, level);
+        match groups.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, members)) => members.push(paragraph),
+            None => groups.push((key, vec![paragraph])),
+        }
+    }
+    groups
+}
+
+/// Index range of the main content block: the run of consecutive
+/// [`ClassType::Good`] paragraphs with the most words in total.
+///
+/// Ties go to the earliest run. Returns an empty range (`0..0`) when no paragraph is
+/// Good. Slice the classified paragraphs with the result to drop Good intro/outro
+/// fragments that sit outside the article body.
+pub fn main_content_block(paragraphs: &[Paragraph]) -> Range<usize> {
+    let mut best = 0..0;
+    let mut best_words = 0;
+    let mut start = 0;
+    let mut words = 0;
+    for (i, paragraph) in paragraphs.iter().enumerate() {
+        if paragraph.class_type != ClassType::Good {
+            start = i + 1;
+            words = 0;
+            continue;
+        }
+        words += paragraph.words_count;
+        if best.is_empty() || words > best_words {
+            best = start..i + 1;
+            best_words = words;
+        }
+    }
+    best
+}
+
+/// Pair each paragraph with its normalized position: `index / (count - 1)`.
+///
+/// The first paragraph is at `0.0` and the last at `1.0`. A single paragraph is at
+/// `0.0`.
+pub fn with_positions(paragraphs: Vec<Paragraph>) -> Vec<(f64, Paragraph)> {
+    let last = paragraphs.len().saturating_sub(1).max(1) as f64;
+    paragraphs
+        .into_iter()
+        .enumerate()
+        .map(|(i, p)| (i as f64 / last, p))
+        .collect()
+}
+
+/// Good-word count at which [`document_score`]'s length term saturates.
+const SCORE_FULL_WORDS: f64 = 500.0;
+
+/// Average good-paragraph stopword density at which the density term saturates.
+const SCORE_FULL_DENSITY: f64 = 0.4;
+
+/// A 0.0–1.0 content-quality score for a classified document, for ranking pages.
+///
+/// `score = 0.4 * ratio + 0.4 * length + 0.2 * density`, where
+/// - `ratio` is the share of the document's characters in good paragraphs,
+/// - `length` is `min(good_words / 500, 1)`,
+/// - `density` is `min(mean_stopword_density / 0.4, 1)` over good paragraphs.
+///
+/// Pages without good paragraphs score `0.0`; a long, mostly-content article scores
+/// close to `1.0`. Needs classified paragraphs, since it reads `stopword_density`.
+pub fn document_score(paragraphs: &[Paragraph]) -> f64 {
+    let good: Vec<&Paragraph> = paragraphs.iter().filter(|p| !p.is_boilerplate()).collect();
+    if good.is_empty() {
+        return 0.0;
+    }
+    let chars = |p: &&Paragraph| p.text.chars().count();
+    let total_chars: usize = paragraphs.iter().map(|p| p.text.chars().count()).sum();
+    let good_chars: usize = good.iter().map(chars).sum();
+    let ratio = good_chars as f64 / total_chars.max(1) as f64;
+
+    let good_words: usize = good.iter().map(|p| p.words_count).sum();
+    let length = (good_words as f64 / SCORE_FULL_WORDS).min(1.0);
+
+    let mean_density = good.iter().map(|p| p.stopword_density).sum::<f64>() / good.len() as f64;
+    let density = (mean_density / SCORE_FULL_DENSITY).min(1.0);
+
+    0.4 * ratio + 0.4 * length + 0.2 * density
+}
+
+/// First `level` steps of `xpath`, ignoring any `#n` suffix.
+fn container_path(xpath: &str, level: usize) -> String {
+    let xpath = xpath.split('#').next().unwrap_or(xpath);
+    let steps: Vec<&str> = xpath
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .take(level)
+        .collect();
+    format!("/{}", steps.join("/"))
+}
+
+/// Count, across `docs`, how many documents have a Bad paragraph at each `dom_path`.
+///
+/// Each path is counted at most once per document, so a footer with ten Bad links
+/// weighs the same as a one-line footer. Sorted by count, highest first, with ties
+/// broken by path. Paths near the top are a site's recurring boilerplate.
+pub fn boilerplate_path_histogram(
+    docs: &[&str],
+    stoplist: &HashSet<String>,
+    config: &Config,
+) -> Vec<(String, usize)> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for html in docs {
+        let paths: HashSet<String> = justext(html, stoplist, config)
+            .into_iter()
+            .filter(|p| p.class_type == ClassType::Bad)
+            .map(|p| p.dom_path)
+            .collect();
+        for path in paths {
+            *counts.entry(path).or_insert(0) += 1;
+        }
+    }
+    let mut histogram: Vec<(String, usize)> = counts.into_iter().collect();
+    histogram.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    histogram
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn para(text: &str, class_type: ClassType) -> Paragraph {
+        let mut p = Paragraph::builder().text(text).build();
+        p.class_type = class_type;
+        p
+    }
+
+    #[test]
+    fn test_main_content_block_picks_larger_run() {
+        let ps = [
+            para("short good intro", ClassType::Good),
+            para("nav", ClassType::Bad),
+            para("one two three four", ClassType::Good),
+            para("five six seven", ClassType::Good),
+            para("eight nine", ClassType::Good),
+            para("footer", ClassType::Bad),
+            para("outro", ClassType::Good),
+        ];
+        assert_eq!(main_content_block(&ps), 2..5);
+    }
+
+    #[test]
+    fn test_main_content_block_ties_pick_earliest() {
+        let ps = [
+            para("one two", ClassType::Good),
+            para("nav", ClassType::NearGood),
+            para("three four", ClassType::Good),
+        ];
+        assert_eq!(main_content_block(&ps), 0..1);
+    }
+
+    #[test]
+    fn test_main_content_block_no_good() {
+        assert_eq!(main_content_block(&[]), 0..0);
+        assert_eq!(main_content_block(&[para("x", ClassType::Bad)]), 0..0);
+    }
+
+    #[test]
+    fn test_with_positions() {
+        let ps: Vec<Paragraph> = (0..5).map(|_| para("text", ClassType::Good)).collect();
+        let ratios: Vec<f64> = with_positions(ps).into_iter().map(|(r, _)| r).collect();
+        assert_eq!(ratios, [0.0, 0.25, 0.5, 0.75, 1.0]);
+
+        let single = with_positions(vec![para("only", ClassType::Good)]);
+        assert_eq!(single[0].0, 0.0);
+        assert!(with_positions(Vec::new()).is_empty());
+    }
+
+    #[test]
+    fn test_container_path() {
+        assert_eq!(
+            container_path("/html[1]/body[1]/div[2]/p[1]", 3),
+            "/html[1]/body[1]/div[2]"
+        );
+        assert_eq!(container_path("/html[1]/body[1]#2", 3), "/html[1]/body[1]");
+        assert_eq!(container_path("/html[1]/body[1]/div[1]", 0), "/");
+    }
+}
@@ -0,0 +1,195 @@
+// Reader-mode extraction: title, byline, and content in one call.
+
+use scraper::{Html, Selector};
+
+use crate::error::JustextError;
+use crate::output::paragraphs_to_html;
+use crate::paragraph::Paragraph;
+use crate::{justext, resolve_language, Config};
+
+/// How many paragraphs after the title to search for a byline.
+const BYLINE_WINDOW: usize = 5;
+
+/// Maximum words in a byline paragraph, including the leading "By".
+const MAX_BYLINE_WORDS: usize = 12;
+
+/// Structured article data for reader-mode display.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Article {
+    /// Article title, if one could be found.
+    pub title: Option<String>,
+    /// Author credit without the leading "By", e.g. `"Jane Doe"`.
+    pub byline: Option<String>,
+    /// Good paragraph text, one paragraph per line.
+    pub content_text: String,
+    /// Good paragraphs rendered as `<p>`/`<hN>` elements, one per line.
+    pub content_html: String,
+}
+
+/// Extract the title, byline, and main content of an article page.
+///
+/// - `title`: `<meta property="og:title">`, else the first `<h1>`, else `<title>`.
+/// - `byline`: the first short paragraph starting with "By " within a few paragraphs
+///   after the title heading (or the top of the page when there is no heading).
+/// - `content_text` / `content_html`: the good paragraphs, excluding the byline and a
+///   heading that merely repeats the title.
+///
+/// Missing pieces come back as `None` or empty strings; only an unknown `language`
+/// is an error.
+pub fn extract_article(
+    html: &str,
+    language: &str,
+    config: &Config,
+) -> Result<Article, JustextError> {
+    let (stoplist, config) = resolve_language(language, config)?;
+    let paragraphs = justext(html, &stoplist, &config);
+    let title = extract_title(&Html::parse_document(html));
+
+    let title_index = title.as_deref().and_then(|t| {
+        paragraphs
+            .iter()
+            .position(|p| p.heading && same_text(&p.text, t))
+    });
+    let search_from = title_index.map_or(0, |i| i + 1);
+    let byline_index = paragraphs
+        .iter()
+        .enumerate()
+        .skip(search_from)
+        .take(BYLINE_WINDOW)
+        .find(|(_, p)| byline_name(&p.text).is_some())
+        .map(|(i, _)| i);
+    let byline = byline_index.and_then(|i| byline_name(&paragraphs[i].text));
+
+    let content: Vec<&Paragraph> = paragraphs
+        .iter()
+        .enumerate()
+        .filter(|&(i, p)| !p.is_boilerplate() && Some(i) != title_index && Some(i) != byline_index)
+        .map(|(_, p)| p)
+        .collect();
+
+    Ok(Article {
+        title,
+        byline,
+        content_text: content
+            .iter()
+            .map(|p| p.text.as_str())
+            .collect::<Vec<_>>()
+            .join("\n"),
+        content_html: paragraphs_to_html(content),
+    })
+}
+
+fn extract_title(doc: &Html) -> Option<String> {
+    let og = Selector::parse(r#"meta[property="og:title"]"#).unwrap();
+    let h1 = Selector::parse("h1").unwrap();
+    let title = Selector::parse("title").unwrap();
+    doc.select(&og)
+        .filter_map(|m| m.value().attr("content"))
+        .map(collapse_whitespace)
+        .find(|t| !t.is_empty())
+        .or_else(|| {
+            doc.select(&h1)
+                .chain(doc.select(&title))
+                .map(|el| collapse_whitespace(&el.text().collect::<String>()))
+                .find(|t| !t.is_empty())
+        })
+}
+
+/// The name in a "By Jane Doe" line, or `None` if `text` isn't a short byline.
+fn byline_name(text: &str) -> Option<String> {
+    let text = collapse_whitespace(text);
+    let (by, name) = text.split_once(' ')?;
+    if !by.eq_ignore_ascii_case("by") || text.split(' ').count() > MAX_BYLINE_WORDS {
+        return None;
+    }
+    let name = name.trim();
+    (!name.is_empty()).then(|| name.to_string())
+}
+
+fn same_text(a: &str, b: &str) -> bool {
+    collapse_whitespace(a) == collapse_whitespace(b)
+}
+
+fn collapse_whitespace(s: &str) -> String {
+    s.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// A run of good paragraphs under one heading; see [`extract_sections`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Section {
+    /// Heading text, or `None` for content before the first heading.
+    pub heading: Option<String>,
+    /// Heading level (`2` for `<h2>`); `0` when there is no heading.
+    pub level: u8,
+    /// Good paragraph texts under the heading, in document order.
+    pub paragraphs: Vec<String>,
+}
+
+/// Split the good content of a page into sections at each good heading.
+///
+/// Good paragraphs before the first good heading form a leading section with no
+/// heading and level `0` (omitted if there are none). Headings without content keep
+/// their own, empty section. Only an unknown `language` is an error.
+pub fn extract_sections(
+    html: &str,
+    language: &str,
+    config: &Config,
+) -> Result<Vec<Section>, JustextError> {
+    let (stoplist, config) = resolve_language(language, config)?;
+    let mut sections: Vec<Section> = Vec::new();
+    for p in justext(html, &stoplist, &config) {
+        if p.is_boilerplate() {
+            continue;
+        }
+        match p.heading_level().filter(|_| p.heading) {
+            Some(level) => sections.push(Section {
+                heading: Some(p.text),
+                level,
+                paragraphs: Vec::new(),
+            }),
+            None => match sections.last_mut() {
+                Some(section) => section.paragraphs.push(p.text),
+                None => sections.push(Section {
+                    heading: None,
+                    level: 0,
+                    paragraphs: vec![p.text],
+                }),
+            },
+        }
+    }
+    Ok(sections)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_byline_name() {
+        assert_eq!(byline_name("By Jane Doe").as_deref(), Some("Jane Doe"));
+        assert_eq!(
+            byline_name("by  Jane Doe and John Roe").as_deref(),
+            Some("Jane Doe and John Roe")
+        );
+        assert_eq!(byline_name("Bystanders watched"), None);
+        assert_eq!(byline_name("By"), None);
+        assert_eq!(
+            byline_name("By the time the council met, the decision had already been made public"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_title_fallbacks() {
+        let doc = Html::parse_document("<title> Page </title><h1>Headline</h1>");
+        assert_eq!(extract_title(&doc).as_deref(), Some("Headline"));
+        let doc = Html::parse_document("<title> Page  title </title><p>x</p>");
+        assert_eq!(extract_title(&doc).as_deref(), Some("Page title"));
+        let doc =
+            Html::parse_document("<meta property=\"og:title\" content=\"OG\"><h1>Headline</h1>");
+        assert_eq!(extract_title(&doc).as_deref(), Some("OG"));
+        assert_eq!(extract_title(&Html::parse_document("<p>x</p>")), None);
+    }
+}
@@ -70,10 +70,7 @@ fn main() {
         };
 
         // Match Python's UTF-8 → latin-1 fallback.
-        let html = match String::from_utf8(raw.clone()) {
-            Ok(s) => s,
-            Err(_) => raw.iter().map(|&b| b as char).collect(),
-        };
+        let html = justext::decode_html_bytes(&raw);
 
         let paragraphs = justext::justext(&html, &stoplist, &config);
         let text: String = paragraphs
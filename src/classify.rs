@@ -2,51 +2,333 @@
 
 use std::collections::HashSet;
 
-use crate::paragraph::{ClassType, Paragraph};
-use crate::Config;
+use crate::paragraph::{ClassReason, ClassType, Paragraph};
+use crate::{Config, WordSegmentation};
+
+/// Returns true if the text carries a copyright marker (`©`, a literal `&copy`, or one
+/// of `extra`).
+pub(crate) fn has_copyright_marker(text: &str, extra: &[String]) -> bool {
+    text.contains('\u{00A9}')
+        || text.contains("&copy")
+        || extra.iter().any(|marker| text.contains(marker.as_str()))
+}
+
+/// Returns true if a paragraph of at least `length_low` chars exceeds `max_uppercase_ratio`.
+///
+/// Shorter paragraphs are left to the normal Short handling so acronym-heavy
+/// fragments ("NASA and the ESA") aren't misclassified.
+fn is_shouting(paragraph: &Paragraph, length: usize, config: &Config) -> bool {
+    config
+        .max_uppercase_ratio
+        .is_some_and(|max| length >= config.length_low && paragraph.uppercase_ratio() > max)
+}
+
+/// Returns true if the paragraph's alphabetic ratio is below `min_alpha_ratio`.
+fn is_mostly_symbols(paragraph: &Paragraph, config: &Config) -> bool {
+    config
+        .min_alpha_ratio
+        .is_some_and(|min| paragraph.alpha_ratio() < min)
+}
+
+/// Month names and abbreviations counted as date tokens by [`is_metadata_line`].
+const MONTHS: &[&str] = &[
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+    "jan",
+    "feb",
+    "mar",
+    "apr",
+    "jun",
+    "jul",
+    "aug",
+    "sep",
+    "sept",
+    "oct",
+    "nov",
+    "dec",
+];
+
+/// Phrases that mark a byline or timestamp line.
+const METADATA_MARKERS: &[&str] = &["min read", "updated", "published", "posted"];
+
+/// Returns true if `text` reads like a byline or timestamp rather than content.
+///
+/// The line must be at most 20 words, carry a byline marker (leading "By", a
+/// `·`/`|`/`•` separator, or a phrase from [`METADATA_MARKERS`]), and have at least a
+/// quarter of its words be numbers or month names. Bare separators aren't words.
+fn is_metadata_line(text: &str) -> bool {
+    let lower = text.to_lowercase();
+    let words: Vec<&str> = lower
+        .split_whitespace()
+        .map(|w| w.trim_matches(|c: char| !c.is_alphanumeric()))
+        .filter(|w| !w.is_empty())
+        .collect();
+    if words.is_empty() || words.len() > 20 {
+        return false;
+    }
+    let marked = words[0] == "by"
+        || lower.contains(['\u{00B7}', '|', '\u{2022}'])
+        || METADATA_MARKERS.iter().any(|m| lower.contains(m));
+    let dates = words
+        .iter()
+        .filter(|w| w.chars().any(|c| c.is_ascii_digit()) || MONTHS.contains(w))
+        .count();
+    marked && dates * 4 >= words.len()
+}
+
+/// ARIA landmark roles that mark boilerplate regions.
+const BOILERPLATE_LANDMARKS: &[&str] = &["navigation", "banner", "contentinfo"];
+
+/// Returns true if the paragraph sits in a boilerplate landmark and the config drops those.
+fn in_boilerplate_landmark(paragraph: &Paragraph, config: &Config) -> bool {
+    config.drop_landmark_boilerplate
+        && paragraph
+            .landmark
+            .as_deref()
+            .is_some_and(|role| BOILERPLATE_LANDMARKS.contains(&role))
+}
+
+/// Snowball stemmer for a jusText language name (case-insensitive).
+#[cfg(feature = "rust-stemmers")]
+fn stemmer(language: &str) -> Option<rust_stemmers::Stemmer> {
+    use rust_stemmers::Algorithm;
+
+    let algorithm = match language.to_lowercase().as_str() {
+        "arabic" => Algorithm::Arabic,
+        "danish" => Algorithm::Danish,
+        "dutch" => Algorithm::Dutch,
+        "english" => Algorithm::English,
+        "finnish" => Algorithm::Finnish,
+        "french" => Algorithm::French,
+        "german" => Algorithm::German,
+        "greek" => Algorithm::Greek,
+        "hungarian" => Algorithm::Hungarian,
+        "italian" => Algorithm::Italian,
+        "norwegian_bokmal" | "norwegian_nynorsk" => Algorithm::Norwegian,
+        "portuguese" => Algorithm::Portuguese,
+        "romanian" => Algorithm::Romanian,
+        "russian" => Algorithm::Russian,
+        "spanish" => Algorithm::Spanish,
+        "swedish" => Algorithm::Swedish,
+        "tamil" => Algorithm::Tamil,
+        "turkish" => Algorithm::Turkish,
+        _ => return None,
+    };
+    Some(rust_stemmers::Stemmer::create(algorithm))
+}
+
+/// Computes stopword density over the words of `Config::word_segmentation`, through
+/// word stems when `Config::stem_for_matching` asks.
+struct StopwordMatcher<'a> {
+    stoplist: &'a HashSet<String>,
+    segmentation: WordSegmentation,
+    /// The stemmer, and the stoplist's stems for `StemMatching::TokensAndStoplist`.
+    #[cfg(feature = "rust-stemmers")]
+    stemming: Option<(rust_stemmers::Stemmer, HashSet<String>)>,
+}
+
+impl<'a> StopwordMatcher<'a> {
+    fn new(stoplist: &'a HashSet<String>, config: &Config) -> Self {
+        #[cfg(feature = "rust-stemmers")]
+        let stemming = match config.stem_for_matching {
+            crate::StemMatching::Off => None,
+            mode => config
+                .stemming_language
+                .as_deref()
+                .and_then(stemmer)
+                .map(|stemmer| {
+                    let stems = if mode == crate::StemMatching::TokensAndStoplist {
+                        stoplist
+                            .iter()
+                            .map(|w| stemmer.stem(w).into_owned())
+                            .collect()
+                    } else {
+                        HashSet::new()
+                    };
+                    (stemmer, stems)
+                }),
+        };
+        Self {
+            stoplist,
+            segmentation: config.word_segmentation,
+            #[cfg(feature = "rust-stemmers")]
+            stemming,
+        }
+    }
+
+    /// Whether exact matching on whitespace-split words suffices, as in Python jusText.
+    fn is_plain(&self) -> bool {
+        #[cfg(feature = "rust-stemmers")]
+        if self.stemming.is_some() {
+            return false;
+        }
+        self.segmentation == WordSegmentation::Whitespace
+    }
+
+    fn is_stopword(&self, word: &str) -> bool {
+        if self.stoplist.contains(word) {
+            return true;
+        }
+        #[cfg(feature = "rust-stemmers")]
+        if let Some((stemmer, stems)) = &self.stemming {
+            let stem = stemmer.stem(word);
+            return self.stoplist.contains(stem.as_ref()) || stems.contains(stem.as_ref());
+        }
+        false
+    }
+
+    fn density(&self, paragraph: &Paragraph) -> f64 {
+        if self.is_plain() {
+            return paragraph.stopwords_density(self.stoplist);
+        }
+        let words = self.segmentation.split(&paragraph.text);
+        if words.is_empty() {
+            return 0.0;
+        }
+        let count = words
+            .iter()
+            .filter(|word| self.is_stopword(&word.to_lowercase()))
+            .count();
+        count as f64 / words.len() as f64
+    }
+}
+
+/// Threshold multiplier at the first and last paragraph under `position_weighting`.
+const EDGE_TIGHTENING: f64 = 1.25;
+/// Threshold multiplier at the middle paragraph under `position_weighting`.
+const MIDDLE_RELAXATION: f64 = 0.9;
+
+/// Multiplier for the length and stopword thresholds of paragraph `index` of `count`.
+///
+/// With `d` the paragraph's distance from the nearer end of the document as a fraction
+/// of its length (`0.0` at the first or last paragraph, `0.5` in the middle) and `edge`
+/// the edge fraction, the curve is piecewise linear: from 1.25 at `d = 0` down to 1.0 at
+/// `d = edge`, then on down to 0.9 at `d = 0.5`. A lone paragraph counts as the middle.
+fn position_factor(index: usize, count: usize, edge: f64) -> f64 {
+    let edge = edge.clamp(0.0, 0.5);
+    let t = if count > 1 {
+        index as f64 / (count - 1) as f64
+    } else {
+        0.5
+    };
+    let d = t.min(1.0 - t);
+    if d < edge {
+        EDGE_TIGHTENING - (EDGE_TIGHTENING - 1.0) * d / edge
+    } else if edge < 0.5 {
+        1.0 - (1.0 - MIDDLE_RELAXATION) * (d - edge) / (0.5 - edge)
+    } else {
+        1.0
+    }
+}
+
+/// Length and stopword thresholds in effect for one paragraph.
+struct Thresholds {
+    length_low: usize,
+    length_high: usize,
+    stopwords_low: f64,
+    stopwords_high: f64,
+}
+
+impl Thresholds {
+    fn new(config: &Config, index: usize, count: usize) -> Self {
+        let factor = if config.position_weighting {
+            position_factor(index, count, config.position_edge_fraction)
+        } else {
+            1.0
+        };
+        let scale = |n: usize| (n as f64 * factor).round() as usize;
+        Self {
+            length_low: scale(config.length_low),
+            length_high: scale(config.length_high),
+            stopwords_low: (config.stopwords_low * factor).min(1.0),
+            stopwords_high: (config.stopwords_high * factor).min(1.0),
+        }
+    }
+}
 
 /// Context-free classification of paragraphs.
 ///
 /// Sets `initial_class` on each paragraph. Decision tree matches Python exactly.
-#[allow(clippy::if_same_then_else)]
 pub fn classify_paragraphs(
     paragraphs: &mut [Paragraph],
     stoplist: &HashSet<String>,
     config: &Config,
 ) {
-    for paragraph in paragraphs.iter_mut() {
+    let matcher = StopwordMatcher::new(stoplist, config);
+    let count = paragraphs.len();
+    for (index, paragraph) in paragraphs.iter_mut().enumerate() {
+        let thresholds = Thresholds::new(config, index, count);
+        if config.word_segmentation != WordSegmentation::Whitespace {
+            paragraph.words_count = config.word_segmentation.split(&paragraph.text).len();
+        }
         paragraph.heading = !config.no_headings && paragraph.is_heading();
+        paragraph.is_metadata = config.detect_metadata_lines && is_metadata_line(&paragraph.text);
 
-        // Python uses len(paragraph) which is len(paragraph.text) — character count, not bytes.
-        let length = paragraph.text.chars().count();
+        // Python uses len(paragraph) which is len(paragraph.text) — character count, not
+        // bytes — which is the default `LengthMetric::Chars`.
+        let length = config.length_metric.measure(&paragraph.text);
         let link_density = paragraph.links_density();
-        let stopword_density = paragraph.stopwords_density(stoplist);
+        let stopword_density = matcher.density(paragraph);
+        paragraph.link_density = link_density;
+        paragraph.stopword_density = stopword_density;
 
         // Decision tree mirrors Python classify_paragraphs() exactly — order matters.
         // Three initial branches all return Bad but for distinct semantic reasons.
-        paragraph.initial_class = if link_density > config.max_link_density {
-            ClassType::Bad
-        } else if paragraph.text.contains('\u{00A9}') || paragraph.text.contains("&copy") {
-            ClassType::Bad
+        let (class, reason) = if link_density > config.max_link_density {
+            (ClassType::Bad, ClassReason::LinkDensityTooHigh)
+        } else if config.drop_copyright_paragraphs
+            && has_copyright_marker(&paragraph.text, &config.copyright_markers)
+        {
+            (ClassType::Bad, ClassReason::CopyrightMarker)
         } else if paragraph.dom_path.contains("select") {
-            ClassType::Bad
-        } else if length < config.length_low {
+            (ClassType::Bad, ClassReason::InsideSelect)
+        } else if in_boilerplate_landmark(paragraph, config) {
+            (ClassType::Bad, ClassReason::BoilerplateLandmark)
+        } else if paragraph.blocklisted {
+            (ClassType::Bad, ClassReason::Blocklisted)
+        } else if paragraph.interactive && config.drop_interactive {
+            (ClassType::Bad, ClassReason::Interactive)
+        } else if paragraph.is_metadata && config.drop_metadata_lines {
+            (ClassType::Bad, ClassReason::MetadataLine)
+        } else if is_shouting(paragraph, length, config) {
+            (ClassType::Bad, ClassReason::TooMuchUppercase)
+        } else if is_mostly_symbols(paragraph, config) {
+            (ClassType::Bad, ClassReason::TooFewLetters)
+        } else if length < thresholds.length_low {
             if paragraph.chars_count_in_links > 0 {
-                ClassType::Bad
+                (ClassType::Bad, ClassReason::ShortWithLinks)
             } else {
-                ClassType::Short
+                (ClassType::Short, ClassReason::TooShort)
             }
-        } else if stopword_density >= config.stopwords_high {
-            if length > config.length_high {
-                ClassType::Good
+        } else if stopword_density >= thresholds.stopwords_high {
+            if length > thresholds.length_high {
+                (ClassType::Good, ClassReason::GoodByLength)
             } else {
-                ClassType::NearGood
+                (ClassType::NearGood, ClassReason::NearGoodByDensity)
             }
-        } else if stopword_density >= config.stopwords_low {
-            ClassType::NearGood
+        } else if stopword_density >= thresholds.stopwords_low {
+            (ClassType::NearGood, ClassReason::MediumStopwordDensity)
         } else {
-            ClassType::Bad
+            (ClassType::Bad, ClassReason::LowStopwordDensity)
         };
+        paragraph.initial_class = class;
+        paragraph.reason = Some(reason);
+
+        debug_log!(
+            "classify {}: {:?} ({reason:?}; length={length}, stopword_density={stopword_density:.3}, link_density={link_density:.3})",
+            paragraph.xpath,
+            class,
+        );
     }
 }
 
@@ -55,12 +337,13 @@ mod tests {
     use super::*;
     use crate::paragraph_maker::make_paragraphs;
     use crate::preprocess::preprocess;
+    use crate::LengthMetric;
 
     /// Build a paragraph from HTML snippet for testing.
     fn make_paragraph(text: &str, chars_in_links: usize) -> Paragraph {
         let html = format!("<html><body><p>{text}</p></body></html>");
         let doc = preprocess(&html);
-        let mut ps = make_paragraphs(&doc);
+        let mut ps = make_paragraphs(&doc, &Config::default());
         assert!(!ps.is_empty(), "no paragraphs parsed from: {text}");
         ps[0].chars_count_in_links = chars_in_links;
         ps.remove(0)
@@ -74,6 +357,142 @@ mod tests {
         words.iter().map(|w| w.to_string()).collect()
     }
 
+    #[test]
+    fn test_stored_densities_match_methods() {
+        let stoplist = stoplist(&["the", "of", "and"]);
+        let mut ps = vec![
+            make_paragraph("The history of the town and its people", 7),
+            make_paragraph("No stopwords here", 0),
+        ];
+        assert_eq!(ps[0].stopword_density, 0.0);
+        assert_eq!(ps[0].link_density, 0.0);
+
+        classify_paragraphs(&mut ps, &stoplist, &Config::default());
+        for p in &ps {
+            assert_eq!(p.stopword_density, p.stopwords_density(&stoplist));
+            assert_eq!(p.link_density, p.links_density());
+        }
+        assert!(ps[0].stopword_density > 0.0);
+        assert!(ps[0].link_density > 0.0);
+    }
+
+    #[test]
+    fn test_reason_distinguishes_good_routes() {
+        let stoplist = stoplist(&["the", "of", "and", "a", "in", "to", "is"]);
+        let text = "The history of the town is a story of trade and the river, and of the \
+                    people who came to live in the valley and to work in the mills.";
+        assert!(text.chars().count() < 200);
+
+        // Default length_high (200): high density alone → NearGood via density.
+        let mut ps = vec![make_paragraph(text, 0)];
+        assert_eq!(ps[0].reason, None);
+        classify_paragraphs(&mut ps, &stoplist, &Config::default());
+        assert_eq!(ps[0].initial_class, ClassType::NearGood);
+        assert_eq!(ps[0].reason, Some(ClassReason::NearGoodByDensity));
+
+        // Lower length_high so the same paragraph is also long enough → Good by length.
+        let config = Config::default().with_length_high(100);
+        classify_paragraphs(&mut ps, &stoplist, &config);
+        assert_eq!(ps[0].initial_class, ClassType::Good);
+        assert_eq!(ps[0].reason, Some(ClassReason::GoodByLength));
+    }
+
+    #[test]
+    fn test_reason_for_bad_and_short_branches() {
+        let stoplist = stoplist(&["the"]);
+        let mut ps = vec![
+            make_paragraph("Home", 4),
+            make_paragraph("Short text", 0),
+            make_paragraph(
+                "Quarterly revenue figures exceeded analyst expectations across every major segment",
+                0,
+            ),
+        ];
+        classify_paragraphs(&mut ps, &stoplist, &Config::default());
+        assert_eq!(ps[0].reason, Some(ClassReason::LinkDensityTooHigh));
+        assert_eq!(ps[1].reason, Some(ClassReason::TooShort));
+        assert_eq!(ps[2].reason, Some(ClassReason::LowStopwordDensity));
+        // The alias names the same type.
+        let reason: Option<crate::ClassificationReason> = ps[2].reason;
+        assert_eq!(
+            reason,
+            Some(crate::ClassificationReason::LowStopwordDensity)
+        );
+    }
+
+    #[test]
+    fn test_reason_covers_every_branch() {
+        let stoplist = stoplist(&["the", "of", "and", "a", "in", "to", "is"]);
+        let config = Config::default()
+            .with_stopwords_low(0.2)
+            .with_stopwords_high(0.5)
+            .with_drop_landmark_boilerplate(true)
+            .with_detect_metadata_lines(true)
+            .with_drop_metadata_lines(true)
+            .with_drop_interactive(true)
+            .with_max_uppercase_ratio(Some(0.8))
+            .with_min_alpha_ratio(Some(0.5));
+        let dense = "The history of the town is a story of trade and the river, and of the \
+                     people who came to live in the valley and to work in the mills.";
+
+        let mut in_select = make_paragraph("Option one", 0);
+        in_select.dom_path = "html.body.select.option".to_string();
+        let mut in_nav = make_paragraph("Skip to content", 0);
+        in_nav.landmark = Some("navigation".to_string());
+        let mut blocked = make_paragraph("Sponsored content", 0);
+        blocked.blocklisted = true;
+        let mut clickable = make_paragraph("Click to expand", 0);
+        clickable.interactive = true;
+
+        let cases = vec![
+            (make_paragraph("Home", 4), ClassReason::LinkDensityTooHigh),
+            (make_paragraph("\u{00A9} 2024 Example Corp", 0), ClassReason::CopyrightMarker),
+            (in_select, ClassReason::InsideSelect),
+            (in_nav, ClassReason::BoilerplateLandmark),
+            (blocked, ClassReason::Blocklisted),
+            (clickable, ClassReason::Interactive),
+            (
+                make_paragraph("By Jane Doe \u{00B7} March 3, 2024", 0),
+                ClassReason::MetadataLine,
+            ),
+            (
+                make_paragraph(
+                    "BREAKING NEWS: EVERYTHING YOU NEED TO KNOW ABOUT THE ANNOUNCEMENT MADE TODAY",
+                    0,
+                ),
+                ClassReason::TooMuchUppercase,
+            ),
+            (
+                make_paragraph("\u{2605}\u{2605}\u{2605}\u{2605}\u{2606} 4.5/5 (1,234)", 0),
+                ClassReason::TooFewLetters,
+            ),
+            (make_paragraph("Read more about this topic", 3), ClassReason::ShortWithLinks),
+            (make_paragraph("Short text", 0), ClassReason::TooShort),
+            (make_paragraph(&format!("{dense} {dense}"), 0), ClassReason::GoodByLength),
+            (make_paragraph(dense, 0), ClassReason::NearGoodByDensity),
+            (
+                make_paragraph(
+                    "The quarterly revenue figures of the company exceeded analyst expectations \
+                     in every major segment",
+                    0,
+                ),
+                ClassReason::MediumStopwordDensity,
+            ),
+            (
+                make_paragraph(
+                    "Quarterly revenue figures exceeded analyst expectations across every major segment",
+                    0,
+                ),
+                ClassReason::LowStopwordDensity,
+            ),
+        ];
+        let (mut ps, expected): (Vec<Paragraph>, Vec<ClassReason>) = cases.into_iter().unzip();
+
+        classify_paragraphs(&mut ps, &stoplist, &config);
+        let reasons: Vec<ClassReason> = ps.iter().map(|p| p.reason.unwrap()).collect();
+        assert_eq!(reasons, expected);
+    }
+
     // --- Port of test_classify_paragraphs.py ---
 
     #[test]
@@ -122,6 +541,125 @@ mod tests {
         assert_eq!(paragraphs[1].initial_class, ClassType::Bad);
     }
 
+    #[test]
+    fn test_length_metric_flips_borderline_paragraph() {
+        // 69 characters but 109 bytes: each Cyrillic letter is two bytes in UTF-8.
+        let text = "в и на ".repeat(10);
+        let text = text.trim_end();
+        let stoplist = stoplist(&["в", "и", "на"]);
+        let classify = |metric: LengthMetric| {
+            let mut ps = vec![make_paragraph(text, 0)];
+            let config = Config::default()
+                .with_length_high(100)
+                .with_length_metric(metric);
+            classify_paragraphs(&mut ps, &stoplist, &config);
+            ps[0].initial_class
+        };
+        assert_eq!(classify(LengthMetric::Chars), ClassType::Short);
+        assert_eq!(classify(LengthMetric::Bytes), ClassType::Good);
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    #[test]
+    fn test_length_metric_graphemes() {
+        // "é" spelled as e + combining acute accent.
+        let text = "cafe\u{301}";
+        assert_eq!(LengthMetric::Chars.measure(text), 5);
+        assert_eq!(LengthMetric::Bytes.measure(text), 6);
+        assert_eq!(LengthMetric::Graphemes.measure(text), 4);
+    }
+
+    #[cfg(feature = "rust-stemmers")]
+    #[test]
+    fn test_stem_for_matching() {
+        use crate::StemMatching;
+
+        let density = |stoplist: &HashSet<String>, text: &str, mode: StemMatching| {
+            let config = Config::default()
+                .with_stem_for_matching(mode)
+                .with_stemming_language(Some("English".to_string()));
+            StopwordMatcher::new(stoplist, &config).density(&make_paragraph(text, 0))
+        };
+        // "runs" stems to "run", which is in the stoplist.
+        let base = stoplist(&["run"]);
+        assert_eq!(density(&base, "runs fast", StemMatching::Off), 0.0);
+        assert_eq!(density(&base, "runs fast", StemMatching::Tokens), 0.5);
+        // "runs" and the stoplist's "running" share the stem "run".
+        let inflected = stoplist(&["running"]);
+        assert_eq!(density(&inflected, "runs fast", StemMatching::Tokens), 0.0);
+        assert_eq!(
+            density(&inflected, "runs fast", StemMatching::TokensAndStoplist),
+            0.5
+        );
+
+        // Languages without a stemmer fall back to exact matching.
+        let config = Config::default()
+            .with_stem_for_matching(StemMatching::Tokens)
+            .with_stemming_language(Some("Klingon".to_string()));
+        let p = make_paragraph("runs fast", 0);
+        assert_eq!(StopwordMatcher::new(&base, &config).density(&p), 0.0);
+
+        assert_eq!(stemmer("German").unwrap().stem("häuser"), "haus");
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    #[test]
+    fn test_word_segmentation_unicode_words() {
+        let text = "这是我的书，我们今天去公园散步。";
+        let stoplist = stoplist(&["是", "的", "我"]);
+
+        let mut ps = vec![make_paragraph(text, 0)];
+        classify_paragraphs(&mut ps, &stoplist, &Config::default());
+        assert_eq!(ps[0].words_count, 1);
+        assert_eq!(ps[0].stopword_density, 0.0);
+
+        // One word per ideograph; the comma and full stop aren't words.
+        let config = Config::default().with_word_segmentation(WordSegmentation::UnicodeWords);
+        classify_paragraphs(&mut ps, &stoplist, &config);
+        assert_eq!(ps[0].words_count, 14);
+        assert!((ps[0].stopword_density - 4.0 / 14.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_position_factor_curve() {
+        assert_eq!(position_factor(0, 11, 0.2), 1.25);
+        assert_eq!(position_factor(10, 11, 0.2), 1.25);
+        assert!((position_factor(1, 11, 0.2) - 1.125).abs() < 1e-9);
+        assert_eq!(position_factor(2, 11, 0.2), 1.0);
+        assert!((position_factor(5, 11, 0.2) - 0.9).abs() < 1e-9);
+        assert!((position_factor(0, 1, 0.2) - 0.9).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_position_weighting() {
+        // 7 stopwords in 20 words (density 0.35) and about 220 characters: Good with the
+        // default thresholds (0.32, 200), Bad at the top edge (0.375 for NearGood) and
+        // still Good in the middle.
+        let stoplist = stoplist(&["the"]);
+        let mut words = vec!["extraordinary"; 13];
+        words.extend(["the"; 7]);
+        let borderline = words.join(" ");
+        let filler = "filler";
+        let texts = |at: usize| -> Vec<Paragraph> {
+            (0..11)
+                .map(|i| make_paragraph(if i == at { &borderline } else { filler }, 0))
+                .collect()
+        };
+        let class_at = |at: usize, config: &Config| {
+            let mut ps = texts(at);
+            classify_paragraphs(&mut ps, &stoplist, config);
+            ps[at].initial_class
+        };
+
+        let uniform = Config::default();
+        assert_eq!(class_at(0, &uniform), ClassType::Good);
+        assert_eq!(class_at(5, &uniform), ClassType::Good);
+
+        let weighted = Config::default().with_position_weighting(true);
+        assert_eq!(class_at(0, &weighted), ClassType::Bad);
+        assert_eq!(class_at(5, &weighted), ClassType::Good);
+    }
+
     #[test]
     fn test_stopwords_high() {
         let mut paragraphs = vec![
@@ -184,12 +722,52 @@ mod tests {
         assert_eq!(ps[0].initial_class, ClassType::Bad);
     }
 
+    #[test]
+    fn test_extra_copyright_markers() {
+        let classify = |config: &Config| {
+            let mut ps = vec![make_paragraph("無断転載禁止 Example Inc", 0)];
+            classify_paragraphs(&mut ps, &empty_stoplist(), config);
+            ps[0].reason
+        };
+        assert_ne!(
+            classify(&Config::default()),
+            Some(ClassReason::CopyrightMarker)
+        );
+        let config = Config::default().with_copyright_markers(vec!["無断転載禁止".to_string()]);
+        assert_eq!(classify(&config), Some(ClassReason::CopyrightMarker));
+    }
+
+    #[test]
+    fn test_drop_copyright_paragraphs_off() {
+        let text = "Under the law, the \u{00A9} symbol is not required for a work to be \
+                    protected, but it is a notice that tells the reader who owns the rights \
+                    to the work and when it was first published, which can be useful in court.";
+        let stoplist = stoplist(&[
+            "the", "is", "not", "for", "a", "to", "be", "but", "it", "that", "who", "and", "when",
+            "was", "which", "can", "in", "of",
+        ]);
+        let classify = |config: &Config| {
+            let mut ps = vec![make_paragraph(text, 0)];
+            classify_paragraphs(&mut ps, &stoplist, config);
+            (ps[0].initial_class, ps[0].reason)
+        };
+        assert_eq!(
+            classify(&Config::default()),
+            (ClassType::Bad, Some(ClassReason::CopyrightMarker))
+        );
+        let config = Config::default().with_drop_copyright_paragraphs(false);
+        assert_eq!(
+            classify(&config),
+            (ClassType::Good, Some(ClassReason::GoodByLength))
+        );
+    }
+
     #[test]
     fn test_select_in_dom_path() {
         // Paragraph inside a <select> element
         let html = "<html><body><select><option>Choose</option></select></body></html>";
         let doc = preprocess(html);
-        let mut ps = make_paragraphs(&doc);
+        let mut ps = make_paragraphs(&doc, &Config::default());
         if ps.is_empty() {
             return; // select removed by preprocessor — acceptable
         }
@@ -201,11 +779,95 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_drop_landmark_boilerplate() {
+        let long = "This paragraph contains many common English stopwords and it is long \
+                    enough to be classified as good content with the English stoplist applied \
+                    correctly by the justext algorithm when processing this article text here.";
+        let html = format!(
+            "<html><body><div role=\"navigation\"><p>{long}</p></div>\
+             <div role=\"main\"><p>{long}</p></div></body></html>"
+        );
+        let stoplist = stoplist(&[
+            "this", "and", "it", "is", "to", "be", "as", "with", "the", "by", "when",
+        ]);
+
+        let doc = preprocess(&html);
+        let mut ps = make_paragraphs(&doc, &Config::default());
+        classify_paragraphs(&mut ps, &stoplist, &Config::default());
+        assert_eq!(ps[0].initial_class, ClassType::Good);
+        assert_eq!(ps[1].initial_class, ClassType::Good);
+
+        let config = Config::default().with_drop_landmark_boilerplate(true);
+        classify_paragraphs(&mut ps, &stoplist, &config);
+        assert_eq!(ps[0].initial_class, ClassType::Bad);
+        assert_eq!(
+            ps[1].initial_class,
+            ClassType::Good,
+            "role=main is never dropped"
+        );
+    }
+
+    #[test]
+    fn test_max_uppercase_ratio_flags_banner() {
+        let banner =
+            "LIMITED TIME OFFER: SUBSCRIBE TODAY AND GET THE FIRST THREE MONTHS FOR FREE WITH CODE";
+        let stoplist = stoplist(&["and", "the", "for", "with", "get", "today"]);
+        let mut ps = vec![make_paragraph(banner, 0)];
+
+        classify_paragraphs(&mut ps, &stoplist, &Config::default());
+        assert_eq!(ps[0].initial_class, ClassType::NearGood);
+
+        let config = Config::default().with_max_uppercase_ratio(Some(0.8));
+        classify_paragraphs(&mut ps, &stoplist, &config);
+        assert_eq!(ps[0].initial_class, ClassType::Bad);
+    }
+
+    #[test]
+    fn test_max_uppercase_ratio_skips_short_paragraphs() {
+        let mut ps = vec![make_paragraph("NASA AND ESA", 0)];
+        let config = Config::default().with_max_uppercase_ratio(Some(0.5));
+        classify_paragraphs(&mut ps, &empty_stoplist(), &config);
+        assert_eq!(ps[0].initial_class, ClassType::Short);
+    }
+
+    #[test]
+    fn test_min_alpha_ratio_flags_emoji_rating() {
+        let config = Config::default().with_min_alpha_ratio(Some(0.5));
+        let mut ps = vec![make_paragraph(
+            "\u{2B50}\u{2B50}\u{2B50}\u{2B50}\u{2606} 4.2 \u{1F44D} 1,024 \u{1F44E} 37",
+            0,
+        )];
+        classify_paragraphs(&mut ps, &empty_stoplist(), &Config::default());
+        assert_eq!(ps[0].initial_class, ClassType::Short);
+        classify_paragraphs(&mut ps, &empty_stoplist(), &config);
+        assert_eq!(ps[0].initial_class, ClassType::Bad);
+    }
+
+    #[test]
+    fn test_min_alpha_ratio_keeps_prose_with_emoji() {
+        let config = Config::default().with_min_alpha_ratio(Some(0.5));
+        let stoplist = stoplist(&["the", "was", "and", "of", "a", "it", "to"]);
+        let mut ps = vec![make_paragraph(
+            "The concert was a joy and the crowd sang along to every one of the songs \u{1F389} it was great",
+            0,
+        )];
+        classify_paragraphs(&mut ps, &stoplist, &config);
+        assert_eq!(ps[0].initial_class, ClassType::NearGood);
+    }
+
+    #[test]
+    fn test_uppercase_ratio_ignores_non_letters() {
+        let p = make_paragraph("ABC def 123 !!!", 0);
+        assert!((p.uppercase_ratio() - 0.5).abs() < 1e-9);
+        assert_eq!(make_paragraph("123 456", 0).uppercase_ratio(), 0.0);
+    }
+
     #[test]
     fn test_heading_detection() {
         let html = "<html><body><h1>A heading</h1><p>body text here</p></body></html>";
         let doc = preprocess(html);
-        let mut ps = make_paragraphs(&doc);
+        let mut ps = make_paragraphs(&doc, &Config::default());
         let config = Config::default();
         classify_paragraphs(&mut ps, &empty_stoplist(), &config);
         assert!(ps[0].heading, "h1 paragraph should be marked as heading");
@@ -216,7 +878,7 @@ mod tests {
     fn test_no_headings_config() {
         let html = "<html><body><h1>A heading</h1></body></html>";
         let doc = preprocess(html);
-        let mut ps = make_paragraphs(&doc);
+        let mut ps = make_paragraphs(&doc, &Config::default());
         let config = Config {
             no_headings: true,
             ..Config::default()
@@ -227,4 +889,28 @@ mod tests {
             "heading should be false when no_headings=true"
         );
     }
+
+    #[test]
+    fn test_detect_metadata_lines() {
+        let byline = "By Jane Doe \u{00B7} March 3, 2024 \u{00B7} 5 min read";
+        let mut ps = vec![
+            make_paragraph(byline, 0),
+            make_paragraph("By the river we sat down and wept for 2 days", 0),
+            make_paragraph("Updated 2024-03-03 14:05", 0),
+        ];
+
+        classify_paragraphs(&mut ps, &empty_stoplist(), &Config::default());
+        assert!(ps.iter().all(|p| !p.is_metadata));
+
+        let config = Config::default().with_detect_metadata_lines(true);
+        classify_paragraphs(&mut ps, &empty_stoplist(), &config);
+        let flags: Vec<bool> = ps.iter().map(|p| p.is_metadata).collect();
+        assert_eq!(flags, [true, false, true]);
+        assert_eq!(ps[0].reason, Some(ClassReason::TooShort));
+
+        let config = config.with_drop_metadata_lines(true);
+        classify_paragraphs(&mut ps, &empty_stoplist(), &config);
+        assert_eq!(ps[0].initial_class, ClassType::Bad);
+        assert_eq!(ps[0].reason, Some(ClassReason::MetadataLine));
+    }
 }
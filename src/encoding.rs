@@ -0,0 +1,144 @@
+// Byte-to-text decoding for HTML input of unknown encoding.
+
+/// Decode raw HTML bytes: UTF-8 if valid, otherwise latin-1 (ISO-8859-1).
+///
+/// The latin-1 fallback maps every byte to the code point of the same value, so it
+/// never fails. This matches the decoding used by `scripts/compare_python.py`.
+pub fn decode_html_bytes(bytes: &[u8]) -> String {
+    match std::str::from_utf8(bytes) {
+        Ok(s) => s.to_string(),
+        Err(_) => bytes.iter().map(|&b| b as char).collect(),
+    }
+}
+
+/// How many leading bytes are searched for a `<meta>` charset declaration, as in the
+/// HTML spec's prescan.
+#[cfg(feature = "encoding")]
+const META_PRESCAN_BYTES: usize = 1024;
+
+/// Decode raw HTML bytes using the charset they declare.
+///
+/// A byte order mark wins; otherwise the first `<meta charset>` or
+/// `<meta http-equiv content="...; charset=...">` in the first 1024 bytes is used. Without
+/// either, bytes that are valid UTF-8 decode as UTF-8 and anything else as Windows-1252
+/// (which, as in browsers, is also what the `ISO-8859-1` label means). Malformed
+/// sequences become U+FFFD. Requires the `encoding` feature.
+#[cfg(feature = "encoding")]
+pub(crate) fn decode_html_bytes_sniffed(bytes: &[u8]) -> String {
+    let encoding = encoding_rs::Encoding::for_bom(bytes)
+        .map(|(encoding, _)| encoding)
+        .or_else(|| meta_charset(&bytes[..bytes.len().min(META_PRESCAN_BYTES)]))
+        .unwrap_or_else(|| {
+            if std::str::from_utf8(bytes).is_ok() {
+                encoding_rs::UTF_8
+            } else {
+                encoding_rs::WINDOWS_1252
+            }
+        });
+    let (text, _, _) = encoding.decode(bytes);
+    text.into_owned()
+}
+
+/// The encoding named by the first `<meta>` tag in `head` that declares one.
+///
+/// A UTF-16 label is taken as UTF-8, since a document readable as ASCII isn't UTF-16.
+#[cfg(feature = "encoding")]
+fn meta_charset(head: &[u8]) -> Option<&'static encoding_rs::Encoding> {
+    let lower = head.to_ascii_lowercase();
+    let mut rest = lower.as_slice();
+    while let Some(start) = find(rest, b"<meta") {
+        let tag = &rest[start..];
+        let end = tag.iter().position(|&b| b == b'>').unwrap_or(tag.len());
+        let attrs = &tag[5..end];
+        if let Some(label) = find(attrs, b"charset").and_then(|i| charset_value(&attrs[i + 7..])) {
+            if let Some(encoding) = encoding_rs::Encoding::for_label(label) {
+                return Some(
+                    if encoding == encoding_rs::UTF_16LE || encoding == encoding_rs::UTF_16BE {
+                        encoding_rs::UTF_8
+                    } else {
+                        encoding
+                    },
+                );
+            }
+        }
+        rest = &tag[end..];
+    }
+    None
+}
+
+/// The label after `charset`: skips whitespace and `=`, then reads a quoted or bare value.
+#[cfg(feature = "encoding")]
+fn charset_value(after: &[u8]) -> Option<&[u8]> {
+    let after = after
+        .trim_ascii_start()
+        .strip_prefix(b"=")?
+        .trim_ascii_start();
+    let (quote, value) = match after.first() {
+        Some(&q @ (b'"' | b'\'')) => (Some(q), &after[1..]),
+        _ => (None, after),
+    };
+    let end = value
+        .iter()
+        .position(|&b| match quote {
+            Some(q) => b == q,
+            None => b.is_ascii_whitespace() || matches!(b, b';' | b'"' | b'\'' | b'/'),
+        })
+        .unwrap_or(value.len());
+    Some(&value[..end]).filter(|label| !label.is_empty())
+}
+
+#[cfg(feature = "encoding")]
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_valid_utf8() {
+        assert_eq!(decode_html_bytes("café €".as_bytes()), "café €");
+    }
+
+    #[test]
+    fn test_decode_invalid_utf8_falls_back_to_latin1() {
+        // 0xE9 is "é" in latin-1 but an incomplete sequence in UTF-8.
+        let bytes = b"<p>caf\xE9 \xA9 2024</p>";
+        assert_eq!(decode_html_bytes(bytes), "<p>café © 2024</p>");
+    }
+
+    #[cfg(feature = "encoding")]
+    #[test]
+    fn test_decode_sniffed() {
+        // Windows-1252 curly quotes (0x93/0x94) and an en dash (0x96), which latin-1 maps
+        // to C1 control characters instead.
+        let quoted = b"<p>\x93Hello\x94 \x96 world</p>";
+        let meta = [b"<meta charset=\"windows-1252\">".as_slice(), quoted].concat();
+        assert_eq!(
+            decode_html_bytes_sniffed(&meta),
+            "<meta charset=\"windows-1252\"><p>\u{201C}Hello\u{201D} \u{2013} world</p>"
+        );
+        // No declaration and not UTF-8: Windows-1252.
+        assert_eq!(
+            decode_html_bytes_sniffed(quoted),
+            "<p>\u{201C}Hello\u{201D} \u{2013} world</p>"
+        );
+
+        let http_equiv =
+            b"<META HTTP-EQUIV='Content-Type' CONTENT='text/html; charset=ISO-8859-5'>\xD0";
+        assert!(decode_html_bytes_sniffed(http_equiv).ends_with('\u{0430}'));
+        // A BOM overrides the meta tag.
+        let bom = [
+            b"\xEF\xBB\xBF<meta charset=windows-1252>".as_slice(),
+            "\u{201C}".as_bytes(),
+        ]
+        .concat();
+        assert!(decode_html_bytes_sniffed(&bom).ends_with('\u{201C}'));
+        assert_eq!(
+            meta_charset(b"<meta charset=klingon><meta charset='utf-8'>"),
+            Some(encoding_rs::UTF_8)
+        );
+        assert_eq!(meta_charset(b"<meta name=charset>"), None);
+    }
+}
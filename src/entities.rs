@@ -0,0 +1,134 @@
+// Named character references, for re-emitting entities the HTML parser decoded.
+
+use std::collections::HashMap;
+
+/// Named entities tracked for fidelity output, with the character each decodes to.
+///
+/// The basic set (`&amp;`, `&lt;`, `&gt;`, `&quot;`, `&apos;`) is omitted: those are
+/// always re-escaped where needed when the cleaned document is serialized.
+const NAMED_ENTITIES: &[(&str, char)] = &[
+    ("nbsp", '\u{00A0}'),
+    ("shy", '\u{00AD}'),
+    ("ensp", '\u{2002}'),
+    ("emsp", '\u{2003}'),
+    ("thinsp", '\u{2009}'),
+    ("ndash", '\u{2013}'),
+    ("mdash", '\u{2014}'),
+    ("lsquo", '\u{2018}'),
+    ("rsquo", '\u{2019}'),
+    ("sbquo", '\u{201A}'),
+    ("ldquo", '\u{201C}'),
+    ("rdquo", '\u{201D}'),
+    ("bdquo", '\u{201E}'),
+    ("laquo", '\u{00AB}'),
+    ("raquo", '\u{00BB}'),
+    ("hellip", '\u{2026}'),
+    ("bull", '\u{2022}'),
+    ("middot", '\u{00B7}'),
+    ("copy", '\u{00A9}'),
+    ("reg", '\u{00AE}'),
+    ("trade", '\u{2122}'),
+    ("deg", '\u{00B0}'),
+    ("plusmn", '\u{00B1}'),
+    ("times", '\u{00D7}'),
+    ("divide", '\u{00F7}'),
+    ("sect", '\u{00A7}'),
+    ("para", '\u{00B6}'),
+    ("cent", '\u{00A2}'),
+    ("pound", '\u{00A3}'),
+    ("yen", '\u{00A5}'),
+    ("euro", '\u{20AC}'),
+    ("dagger", '\u{2020}'),
+    ("Dagger", '\u{2021}'),
+];
+
+/// How `clean_html` writes characters that the source spelled as named entities.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EntityHandling {
+    /// Emit decoded characters (`&mdash;` becomes `—`). Matches the classifier's view.
+    #[default]
+    Decode,
+    /// Re-emit characters that appeared as named entities in the source as those
+    /// entities (`—` becomes `&mdash;` again).
+    Preserve,
+}
+
+/// A named entity found in the source HTML.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedEntity {
+    /// Byte offset of the leading `&` in the source HTML.
+    pub offset: usize,
+    /// Entity name without `&` and `;`, e.g. `"mdash"`.
+    pub name: String,
+    /// The character the entity decodes to.
+    pub character: char,
+}
+
+/// Find every named entity in `html` that the parser will decode.
+///
+/// Only `&name;` references from the crate's table of common typographic entities
+/// are reported; the basic set (`&amp;`, `&lt;`, ...) and numeric references are not.
+/// The scan is lexical, so entities inside comments or scripts are included too.
+pub fn decoded_entities(html: &str) -> Vec<DecodedEntity> {
+    let mut found = Vec::new();
+    for (offset, _) in html.match_indices('&') {
+        let rest = &html[offset + 1..];
+        let Some(end) = rest.find(';') else { break };
+        let name = &rest[..end];
+        if let Some(&(name, character)) = NAMED_ENTITIES.iter().find(|(n, _)| *n == name) {
+            found.push(DecodedEntity {
+                offset,
+                name: name.to_string(),
+                character,
+            });
+        }
+    }
+    found
+}
+
+/// The character a named entity decodes to, for the basic five and [`NAMED_ENTITIES`].
+pub(crate) fn named_entity(name: &str) -> Option<char> {
+    match name {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        _ => NAMED_ENTITIES
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|&(_, c)| c),
+    }
+}
+
+/// Character → entity name for every entity present in `html`.
+pub(crate) fn entity_map(html: &str) -> HashMap<char, &'static str> {
+    NAMED_ENTITIES
+        .iter()
+        .filter(|(name, _)| html.contains(&format!("&{name};")))
+        .map(|&(name, c)| (c, name))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decoded_entities_positions() {
+        let html = "<p>a &mdash; b &amp; c&hellip;</p>";
+        let found = decoded_entities(html);
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].offset, 5);
+        assert_eq!(found[0].name, "mdash");
+        assert_eq!(found[0].character, '—');
+        assert_eq!(found[1].name, "hellip");
+        assert_eq!(&html[found[1].offset..found[1].offset + 8], "&hellip;");
+    }
+
+    #[test]
+    fn test_decoded_entities_ignores_unknown_and_unterminated() {
+        assert!(decoded_entities("AT&T; R&D & more &bogus;").is_empty());
+        assert!(decoded_entities("tail &mdash").is_empty());
+    }
+}
@@ -1,7 +1,36 @@
 use thiserror::Error;
 
+/// An error from a function that takes a language name or a CSS selector.
+///
+/// New variants may be added in minor releases, so `match` on it needs a wildcard arm.
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum JustextError {
     #[error("unknown language: {0}")]
     UnknownLanguage(String),
+    /// A CSS selector that `scraper` could not parse; holds the selector text.
+    #[error("invalid CSS selector: {0}")]
+    InvalidSelector(String),
+}
+
+/// A [`Config`](crate::Config) rejected by [`Config::validate`](crate::Config::validate)
+/// or, with the `serde` feature, by [`Config::from_json`](crate::Config::from_json).
+#[derive(Debug, Clone, PartialEq, Error)]
+#[non_exhaustive]
+pub enum ConfigError {
+    #[error("length_low ({low}) exceeds length_high ({high})")]
+    LengthRange { low: usize, high: usize },
+    #[error("stopwords_low ({low}) exceeds stopwords_high ({high})")]
+    StopwordsRange { low: f64, high: f64 },
+    /// A ratio or density outside `[0.0, 1.0]` (or NaN).
+    #[error("{field} must be within [0.0, 1.0], got {value}")]
+    OutOfRange { field: &'static str, value: f64 },
+    /// A `position_edge_fraction` outside `[0.0, 0.5]` (or NaN).
+    #[error("position_edge_fraction must be within [0.0, 0.5], got {value}")]
+    EdgeFraction { value: f64 },
+    /// [`Config::from_json`](crate::Config::from_json) input that isn't a valid config
+    /// object; holds the parser's message.
+    #[cfg(feature = "serde")]
+    #[error("invalid config JSON: {0}")]
+    Json(String),
 }
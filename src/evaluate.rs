@@ -0,0 +1,85 @@
+// Scoring classifier output against hand-labeled paragraphs.
+
+use crate::paragraph::Paragraph;
+
+/// Precision, recall, and F1 of good-paragraph predictions; see [`evaluate`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Metrics {
+    /// Fraction of predicted-good paragraphs that are labeled good.
+    pub precision: f64,
+    /// Fraction of labeled-good paragraphs that were predicted good.
+    pub recall: f64,
+    /// Harmonic mean of precision and recall.
+    pub f1: f64,
+}
+
+/// Score classified paragraphs against gold labels (`true` = good), aligned by index.
+///
+/// A paragraph counts as predicted good when `!is_boilerplate()`. Returns `None` if
+/// `predicted` and `gold` differ in length, since the alignment would be meaningless.
+/// Ratios with a zero denominator (nothing predicted good, nothing labeled good) are
+/// reported as `0.0`.
+pub fn evaluate(predicted: &[Paragraph], gold: &[bool]) -> Option<Metrics> {
+    if predicted.len() != gold.len() {
+        return None;
+    }
+    let (mut tp, mut fp, mut fn_) = (0usize, 0usize, 0usize);
+    for (paragraph, &is_good) in predicted.iter().zip(gold) {
+        match (!paragraph.is_boilerplate(), is_good) {
+            (true, true) => tp += 1,
+            (true, false) => fp += 1,
+            (false, true) => fn_ += 1,
+            (false, false) => {}
+        }
+    }
+    let ratio = |n: usize, d: usize| if d == 0 { 0.0 } else { n as f64 / d as f64 };
+    let precision = ratio(tp, tp + fp);
+    let recall = ratio(tp, tp + fn_);
+    let f1 = if precision + recall == 0.0 {
+        0.0
+    } else {
+        2.0 * precision * recall / (precision + recall)
+    };
+    Some(Metrics {
+        precision,
+        recall,
+        f1,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::paragraph::ClassType;
+
+    fn para(class_type: ClassType) -> Paragraph {
+        let mut p = Paragraph::builder().text("text").build();
+        p.class_type = class_type;
+        p
+    }
+
+    #[test]
+    fn test_evaluate() {
+        use ClassType::*;
+        let predicted = [
+            para(Good),
+            para(Good),
+            para(Bad),
+            para(NearGood),
+            para(Good),
+        ];
+        let gold = [true, false, true, false, true];
+        // tp = 2, fp = 1, fn = 1
+        let m = evaluate(&predicted, &gold).unwrap();
+        assert!((m.precision - 2.0 / 3.0).abs() < 1e-9);
+        assert!((m.recall - 2.0 / 3.0).abs() < 1e-9);
+        assert!((m.f1 - 2.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_evaluate_edge_cases() {
+        assert_eq!(evaluate(&[para(ClassType::Good)], &[]), None);
+        let m = evaluate(&[para(ClassType::Bad)], &[false]).unwrap();
+        assert_eq!((m.precision, m.recall, m.f1), (0.0, 0.0, 0.0));
+    }
+}
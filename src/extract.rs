@@ -0,0 +1,644 @@
+// Extraction variants layered on top of the core `justext()` pipeline.
+
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+
+use scraper::Html;
+
+use crate::error::JustextError;
+use crate::output::{paragraphs_to_html, paragraphs_to_html_with_ranges};
+use crate::paragraph::{ClassType, Paragraph};
+use crate::{
+    classify_document, justext, paragraph_maker, preprocess, resolve_language, run_pipeline,
+    Config, PreparedDocument,
+};
+
+/// Classify paragraphs, keep the good ones, then apply a caller-supplied predicate.
+///
+/// The predicate sees the fully classified [`Paragraph`] (including `dom_path` and
+/// `xpath`), so it can implement site-specific cleanup on top of the classifier.
+pub fn extract_filtered(
+    html: &str,
+    stoplist: &HashSet<String>,
+    config: &Config,
+    keep: impl Fn(&Paragraph) -> bool,
+) -> Vec<Paragraph> {
+    justext(html, stoplist, config)
+        .into_iter()
+        .filter(|p| !p.is_boilerplate() && keep(p))
+        .collect()
+}
+
+/// Render the good paragraphs as clean HTML, for downstream renderers.
+///
+/// One element per line: headings become `<hN>` at their detected level and other
+/// paragraphs `<p>`, with the text HTML-escaped. Inline markup is not kept.
+pub fn extract_html(html: &str, stoplist: &HashSet<String>, config: &Config) -> String {
+    let paragraphs = justext(html, stoplist, config);
+    paragraphs_to_html(paragraphs.iter().filter(|p| !p.is_boilerplate()))
+}
+
+/// Render the good paragraphs as HTML and report where each one landed.
+///
+/// The HTML has one element per line: `<hN>` for headings and `<p>` otherwise, with
+/// escaped text. The returned ranges are byte offsets into that string, one per good
+/// paragraph in document order; each spans the whole element including its tags, so
+/// slicing the HTML with it yields that paragraph's markup.
+pub fn extract_html_with_ranges(
+    html: &str,
+    stoplist: &HashSet<String>,
+    config: &Config,
+) -> (String, Vec<Range<usize>>) {
+    let paragraphs = justext(html, stoplist, config);
+    paragraphs_to_html_with_ranges(paragraphs.iter().filter(|p| !p.is_boilerplate()))
+}
+
+/// The cleaned document as HTML, with each paragraph's element marked with its class.
+///
+/// The output is the same cleaned DOM the classifier walks (scripts, styles, `<head>`,
+/// comments, and form controls removed), serialized as a full document. The block
+/// element a paragraph came from carries `data-justext-class` with the paragraph's
+/// final class (`"good"`, `"bad"`, `"short"`, or `"neargood"`). An element that yields
+/// several paragraphs (text around a nested block, or a `<br><br>` split) lists each
+/// distinct class once, space-separated, in document order.
+pub fn annotate_html(html: &str, stoplist: &HashSet<String>, config: &Config) -> String {
+    let (doc, paragraphs) = classify_document(&Html::parse_document(html), stoplist, config);
+
+    let mut classes: HashMap<String, String> = HashMap::new();
+    for p in &paragraphs {
+        // Later paragraphs of the same element carry a `#n` suffix.
+        let xpath = p.xpath.split('#').next().unwrap_or_default();
+        let class = p.class_type.as_str();
+        let value = classes.entry(xpath.to_string()).or_default();
+        if value.is_empty() {
+            value.push_str(class);
+        } else if !value.split(' ').any(|c| c == class) {
+            value.push(' ');
+            value.push_str(class);
+        }
+    }
+    preprocess::serialize_annotated(&doc, "data-justext-class", &classes)
+}
+
+/// Per-document paragraph counts by final `class_type`; see [`extract_with_stats`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExtractionStats {
+    /// Number of paragraphs; the four class counts sum to it.
+    pub total: usize,
+    pub good: usize,
+    pub bad: usize,
+    pub short: usize,
+    pub near_good: usize,
+    /// Mean `link_density` over all paragraphs; `0.0` when there are none.
+    pub avg_link_density: f64,
+}
+
+/// Good text (joined with `\n`, as in [`extract_text`](crate::extract_text)) together
+/// with aggregate stats over every paragraph, for monitoring crawl quality.
+pub fn extract_with_stats(
+    html: &str,
+    stoplist: &HashSet<String>,
+    config: &Config,
+) -> (String, ExtractionStats) {
+    let paragraphs = justext(html, stoplist, config);
+    let mut stats = ExtractionStats {
+        total: paragraphs.len(),
+        good: 0,
+        bad: 0,
+        short: 0,
+        near_good: 0,
+        avg_link_density: 0.0,
+    };
+    for p in &paragraphs {
+        match p.class_type {
+            ClassType::Good => stats.good += 1,
+            ClassType::Bad => stats.bad += 1,
+            ClassType::Short => stats.short += 1,
+            ClassType::NearGood => stats.near_good += 1,
+        }
+    }
+    if !paragraphs.is_empty() {
+        stats.avg_link_density =
+            paragraphs.iter().map(|p| p.link_density).sum::<f64>() / paragraphs.len() as f64;
+    }
+    let text = paragraphs
+        .into_iter()
+        .filter(|p| !p.is_boilerplate())
+        .map(|p| p.text)
+        .collect::<Vec<_>>()
+        .join("\n");
+    (text, stats)
+}
+
+/// Good paragraphs of an already-parsed document, each with the inner HTML of the
+/// element it came from.
+///
+/// The element is the one named by the paragraph's `xpath`, serialized with scraper's
+/// [`ElementRef::inner_html`](scraper::ElementRef::inner_html) from the preprocessed
+/// tree, so inline markup such as `<em>` or `<a href>` is kept while scripts, styles,
+/// and form controls are already gone. Paragraphs that share an element (a `#n` xpath,
+/// e.g. text on either side of a nested block) each get that element's whole inner HTML.
+/// Classification is as in [`justext_from_html`](crate::justext_from_html).
+pub fn justext_with_inner_html(
+    doc: &Html,
+    stoplist: &HashSet<String>,
+    config: &Config,
+) -> Vec<(Paragraph, String)> {
+    let (cleaned, paragraphs) = classify_document(doc, stoplist, config);
+    paragraphs
+        .into_iter()
+        .filter(|p| !p.is_boilerplate())
+        .map(|p| {
+            let inner = paragraph_maker::element_at_xpath(&cleaned, &p.xpath)
+                .map(|el| el.inner_html())
+                .unwrap_or_default();
+            (p, inner)
+        })
+        .collect()
+}
+
+/// Good text as Markdown, keeping heading levels and tables.
+///
+/// Good heading paragraphs become ATX headings with one `#` per level, taken from the
+/// innermost `h1`–`h6` in `dom_path` (`h0` is treated as level 1, and levels above 6
+/// as 6). Other good paragraphs are emitted as plain Markdown paragraphs. Blocks are
+/// separated by a blank line. Text is not escaped, except `|` inside table cells.
+///
+/// A `<table>` is kept when at least half of its cell paragraphs are good, and is then
+/// rendered whole as a Markdown table, one row per `<tr>`, so columns stay aligned.
+/// The first row becomes the header when it is in `<thead>` or made of `<th>` cells;
+/// otherwise the header is blank, or with `markdown_skip_headerless_tables` the table's
+/// good cells are emitted as plain paragraphs.
+pub fn extract_markdown(html: &str, stoplist: &HashSet<String>, config: &Config) -> String {
+    let paragraphs = justext(html, stoplist, config);
+    let mut blocks = Vec::new();
+    let mut i = 0;
+    while i < paragraphs.len() {
+        let Some(table) = table_cell(&paragraphs[i].xpath).map(|c| c.table) else {
+            if !paragraphs[i].is_boilerplate() {
+                blocks.push(markdown_block(&paragraphs[i]));
+            }
+            i += 1;
+            continue;
+        };
+        let end = paragraphs[i..]
+            .iter()
+            .position(|p| table_cell(&p.xpath).map(|c| c.table) != Some(table))
+            .map_or(paragraphs.len(), |n| i + n);
+        let cells = &paragraphs[i..end];
+        let good = cells.iter().filter(|p| !p.is_boilerplate()).count();
+        if good * 2 >= cells.len() {
+            match markdown_table(cells, config) {
+                Some(rendered) => blocks.push(rendered),
+                None => blocks.extend(
+                    cells
+                        .iter()
+                        .filter(|p| !p.is_boilerplate())
+                        .map(markdown_block),
+                ),
+            }
+        }
+        i = end;
+    }
+    blocks.join("\n\n")
+}
+
+/// A paragraph as a Markdown heading or plain paragraph.
+fn markdown_block(p: &Paragraph) -> String {
+    match p.heading_level() {
+        Some(level) if p.is_heading() => {
+            format!("{} {}", "#".repeat(level.clamp(1, 6).into()), p.text)
+        }
+        _ => p.text.clone(),
+    }
+}
+
+/// Where a paragraph sits in a table, as XPath prefixes of its innermost cell.
+struct TableCell<'a> {
+    table: &'a str,
+    row: &'a str,
+    cell: &'a str,
+}
+
+/// The innermost `td`/`th` cell containing the paragraph at `xpath`, with its row and
+/// table. `None` outside table cells.
+fn table_cell(xpath: &str) -> Option<TableCell<'_>> {
+    let path = xpath.split('#').next().unwrap_or_default();
+    // Byte offsets of each `/step`, so prefixes can be sliced out.
+    let steps: Vec<usize> = path.match_indices('/').map(|(i, _)| i).collect();
+    let step_at = |k: usize| &path[steps[k] + 1..steps.get(k + 1).copied().unwrap_or(path.len())];
+    let prefix_through = |k: usize| &path[..steps.get(k + 1).copied().unwrap_or(path.len())];
+
+    let cell = (0..steps.len())
+        .rev()
+        .find(|&k| step_at(k).starts_with("td[") || step_at(k).starts_with("th["))?;
+    let row = (0..cell).rev().find(|&k| step_at(k).starts_with("tr["))?;
+    let table = (0..row).rev().find(|&k| step_at(k).starts_with("table["))?;
+    Some(TableCell {
+        table: prefix_through(table),
+        row: prefix_through(row),
+        cell: prefix_through(cell),
+    })
+}
+
+/// Render consecutive paragraphs from one table as a Markdown table.
+///
+/// Paragraphs of the same cell are joined with a space. Returns `None` for a headerless
+/// table when `config.markdown_skip_headerless_tables` is set.
+fn markdown_table(cells: &[Paragraph], config: &Config) -> Option<String> {
+    // (row prefix, cells as (cell prefix, text)).
+    let mut rows: Vec<(&str, Vec<(&str, String)>)> = Vec::new();
+    for p in cells {
+        let Some(loc) = table_cell(&p.xpath) else {
+            continue;
+        };
+        if rows.last().map_or(true, |(row, _)| *row != loc.row) {
+            rows.push((loc.row, Vec::new()));
+        }
+        let row = &mut rows.last_mut().expect("row was just pushed").1;
+        let text = p.text.split_whitespace().collect::<Vec<_>>().join(" ");
+        match row.last_mut() {
+            Some((cell, joined)) if *cell == loc.cell => {
+                joined.push(' ');
+                joined.push_str(&text);
+            }
+            _ => row.push((loc.cell, text)),
+        }
+    }
+
+    let is_header = |(row, cells): &(&str, Vec<(&str, String)>)| {
+        row.contains("/thead[")
+            || cells.iter().all(|(cell, _)| {
+                cell.rsplit('/')
+                    .next()
+                    .is_some_and(|s| s.starts_with("th["))
+            })
+    };
+    let has_header = rows.first().is_some_and(is_header);
+    if !has_header && config.markdown_skip_headerless_tables {
+        return None;
+    }
+    let columns = rows.iter().map(|(_, cells)| cells.len()).max().unwrap_or(0);
+    let render = |cells: &[(&str, String)]| {
+        let mut line = String::from("|");
+        for c in 0..columns {
+            let text = cells.get(c).map_or("", |(_, text)| text.as_str());
+            line.push(' ');
+            line.push_str(&text.replace('|', "\\|"));
+            line.push_str(" |");
+        }
+        line
+    };
+
+    let mut lines = Vec::with_capacity(rows.len() + 2);
+    let body = if has_header {
+        lines.push(render(&rows[0].1));
+        &rows[1..]
+    } else {
+        lines.push(render(&[]));
+        &rows[..]
+    };
+    lines.push(format!("|{}", " --- |".repeat(columns)));
+    lines.extend(body.iter().map(|(_, cells)| render(cells)));
+    Some(lines.join("\n"))
+}
+
+/// Good paragraphs sorted by XPath rather than tree-walk order.
+///
+/// XPaths are compared step by step, with ordinals compared numerically,
+/// so `div[2]` sorts before `div[10]`, and an ancestor before its descendants. The
+/// sort is stable, so paragraphs with equal keys keep document order.
+pub fn extract_sorted_by_xpath(
+    html: &str,
+    stoplist: &HashSet<String>,
+    config: &Config,
+) -> Vec<Paragraph> {
+    let mut good: Vec<Paragraph> = justext(html, stoplist, config)
+        .into_iter()
+        .filter(|p| !p.is_boilerplate())
+        .collect();
+    good.sort_by(|a, b| compare_xpaths(&a.xpath, &b.xpath));
+    good
+}
+
+/// Ordinal-aware XPath comparison.
+///
+/// Steps like `div[10]` are compared by tag name, then by ordinal as a number; a
+/// path that is a prefix of another sorts first. A `#n` de-duplication suffix is
+/// compared numerically last (no suffix counts as `#1`).
+fn compare_xpaths(a: &str, b: &str) -> Ordering {
+    fn key(xpath: &str) -> (Vec<(&str, usize)>, usize) {
+        let (path, suffix) = xpath.split_once('#').unwrap_or((xpath, "1"));
+        let steps = path
+            .split('/')
+            .filter(|s| !s.is_empty())
+            .map(|step| match step.split_once('[') {
+                Some((tag, rest)) => (tag, rest.trim_end_matches(']').parse().unwrap_or(0)),
+                None => (step, 0),
+            })
+            .collect();
+        (steps, suffix.parse().unwrap_or(1))
+    }
+    key(a).cmp(&key(b))
+}
+
+/// Return at most `max_paragraphs` good paragraphs, in document order.
+///
+/// The whole document is still classified (revision needs every paragraph); the cap
+/// only bounds the output. `max_paragraphs = 0` returns nothing.
+pub fn extract_capped(
+    html: &str,
+    stoplist: &HashSet<String>,
+    config: &Config,
+    max_paragraphs: usize,
+) -> Vec<Paragraph> {
+    if max_paragraphs == 0 {
+        return Vec::new();
+    }
+    justext(html, stoplist, config)
+        .into_iter()
+        .filter(|p| !p.is_boilerplate())
+        .take(max_paragraphs)
+        .collect()
+}
+
+/// Return the leading good text of a page, up to `char_budget` characters.
+///
+/// Boilerplate is skipped, so the preview starts at the first good paragraph. Good
+/// paragraphs are joined with `\n` in document order until the budget is reached;
+/// the paragraph that crosses it is cut at the last word that still fits, so no word
+/// is split, and the kept text retains its own separators (such as the line breaks
+/// from `br_as_newline`). Only when even the first word of the first good paragraph is
+/// over budget is that word cut mid-way, so the preview is never empty while there is
+/// good text and a nonzero budget. The budget counts characters (not bytes),
+/// separators included.
+pub fn extract_above_fold(
+    html: &str,
+    stoplist: &HashSet<String>,
+    config: &Config,
+    char_budget: usize,
+) -> String {
+    let mut out = String::new();
+    let mut used = 0;
+    for paragraph in justext(html, stoplist, config) {
+        let text = paragraph.text.trim();
+        if paragraph.is_boilerplate() || text.is_empty() {
+            continue;
+        }
+        let separator = usize::from(!out.is_empty());
+        let remaining = char_budget.saturating_sub(used + separator);
+        let Some((cut, _)) = text.char_indices().nth(remaining) else {
+            if separator == 1 {
+                out.push('\n');
+            }
+            out.push_str(text);
+            used += separator + text.chars().count();
+            continue;
+        };
+        let fitted = if text[cut..].starts_with(char::is_whitespace) {
+            text[..cut].trim_end()
+        } else {
+            text[..cut]
+                .rfind(char::is_whitespace)
+                .map_or("", |i| text[..i].trim_end())
+        };
+        let fitted = if fitted.is_empty() && out.is_empty() {
+            &text[..cut]
+        } else {
+            fitted
+        };
+        if !fitted.is_empty() {
+            if separator == 1 {
+                out.push('\n');
+            }
+            out.push_str(fitted);
+        }
+        break;
+    }
+    out
+}
+
+/// Keep good paragraphs whose word count is at or above the `pct` percentile.
+///
+/// The percentile is computed over the word counts of the good paragraphs only,
+/// using the nearest-rank method; `pct` is a fraction in `0.0..=1.0` (values outside
+/// are clamped). `pct = 0.0` keeps every good paragraph, and `pct = 1.0` keeps only
+/// the longest ones. Paragraphs come back in document order.
+pub fn extract_by_percentile(
+    html: &str,
+    stoplist: &HashSet<String>,
+    config: &Config,
+    pct: f64,
+) -> Vec<Paragraph> {
+    let good: Vec<Paragraph> = justext(html, stoplist, config)
+        .into_iter()
+        .filter(|p| !p.is_boilerplate())
+        .collect();
+    let mut counts: Vec<usize> = good.iter().map(|p| p.words_count).collect();
+    counts.sort_unstable();
+    let Some(threshold) = nearest_rank(&counts, pct) else {
+        return good;
+    };
+    good.into_iter()
+        .filter(|p| p.words_count >= threshold)
+        .collect()
+}
+
+/// Nearest-rank percentile of sorted `values`, or `None` if empty.
+fn nearest_rank(values: &[usize], pct: f64) -> Option<usize> {
+    let pct = if pct.is_nan() {
+        0.0
+    } else {
+        pct.clamp(0.0, 1.0)
+    };
+    let rank = (pct * values.len() as f64).ceil() as usize;
+    values.get(rank.saturating_sub(1)).copied()
+}
+
+/// Good text in a canonical, line-diffable form.
+///
+/// One good paragraph per line, each with whitespace runs (including newlines from
+/// `br_as_newline`) collapsed to a single space and no leading or trailing
+/// whitespace. Lines end in `\n` (never `\r\n`), including the last; a page with no
+/// good text yields an empty string. The same input always yields the same bytes.
+pub fn extract_canonical(html: &str, stoplist: &HashSet<String>, config: &Config) -> String {
+    let mut out = normalized_content(&justext(html, stoplist, config));
+    if !out.is_empty() {
+        out.push('\n');
+    }
+    out
+}
+
+/// Stable 64-bit hash of the extracted content, for change detection across crawls.
+///
+/// Hashes the good paragraphs only, so pages that differ just in navigation, ads, or
+/// other boilerplate hash identically. Each good paragraph's text is normalized by
+/// collapsing every whitespace run to a single space and trimming; paragraphs are
+/// joined with `\n`, and the UTF-8 bytes are hashed with 64-bit FNV-1a (offset basis
+/// `0xcbf29ce484222325`, prime `0x100000001b3`). The algorithm is fixed, so hashes
+/// stay comparable across crate versions as long as classification is unchanged.
+pub fn content_hash(html: &str, stoplist: &HashSet<String>, config: &Config) -> u64 {
+    let paragraphs = justext(html, stoplist, config);
+    fnv1a(normalized_content(&paragraphs).as_bytes())
+}
+
+/// Extract the text only if its [`content_hash`] differs from `previous_hash`.
+///
+/// Returns `None` for an unchanged page, otherwise the good text (as from
+/// [`extract_text_lang`](crate::extract_text_lang)) with its new hash to store for the
+/// next crawl. The hash covers only the normalized good content, so boilerplate churn
+/// doesn't count as a change. The page is classified once.
+pub fn extract_if_changed(
+    html: &str,
+    language: &str,
+    config: &Config,
+    previous_hash: u64,
+) -> Result<Option<(String, u64)>, JustextError> {
+    let (stoplist, config) = resolve_language(language, config)?;
+    let paragraphs = justext(html, &stoplist, &config);
+    let hash = fnv1a(normalized_content(&paragraphs).as_bytes());
+    if hash == previous_hash {
+        return Ok(None);
+    }
+    let text = paragraphs
+        .into_iter()
+        .filter(|p| !p.is_boilerplate())
+        .map(|p| p.text)
+        .collect::<Vec<_>>()
+        .join("\n");
+    Ok(Some((text, hash)))
+}
+
+/// Bucket key for paragraphs without a clear language in [`extract_by_language`].
+pub const UNKNOWN_LANGUAGE: &str = "unknown";
+
+/// Extract good text from a multilingual page, bucketed by language.
+///
+/// Each paragraph is assigned the supplied language whose stoplist gives it the
+/// highest stopword density, and is classified with that language's stoplist and
+/// settings, as in [`justext_lang`](crate::justext_lang) (the document is classified
+/// once per language so revision still sees every paragraph).
+/// Paragraphs with no stopwords from any language, or tied between languages, go to
+/// the [`UNKNOWN_LANGUAGE`] bucket and are kept if any language's run rated them good.
+/// Each bucket holds its good paragraphs joined with `\n`; buckets without good text
+/// are omitted. Unknown language names are an error.
+pub fn extract_by_language(
+    html: &str,
+    languages: &[&str],
+    config: &Config,
+) -> Result<HashMap<String, String>, JustextError> {
+    let resolved = languages
+        .iter()
+        .map(|lang| resolve_language(lang, config))
+        .collect::<Result<Vec<_>, _>>()?;
+    let doc = PreparedDocument::new(&Html::parse_document(html), config);
+    let runs: Vec<Vec<Paragraph>> = resolved
+        .iter()
+        .map(|(stoplist, lang_config)| run_pipeline(&doc, None, None, stoplist, lang_config))
+        .collect();
+
+    let mut buckets: HashMap<String, Vec<&str>> = HashMap::new();
+    for i in 0..runs.first().map_or(0, Vec::len) {
+        let densities: Vec<f64> = runs.iter().map(|run| run[i].stopword_density).collect();
+        let (language, good) = match clear_best(&densities) {
+            Some(best) => (languages[best], !runs[best][i].is_boilerplate()),
+            None => (
+                UNKNOWN_LANGUAGE,
+                runs.iter().any(|run| !run[i].is_boilerplate()),
+            ),
+        };
+        if good {
+            buckets
+                .entry(language.to_string())
+                .or_default()
+                .push(&runs[0][i].text);
+        }
+    }
+    Ok(buckets
+        .into_iter()
+        .map(|(lang, texts)| (lang, texts.join("\n")))
+        .collect())
+}
+
+/// Index of the strictly highest non-zero value, or `None` if absent or tied.
+fn clear_best(values: &[f64]) -> Option<usize> {
+    let (best, &max) = values
+        .iter()
+        .enumerate()
+        .max_by(|a, b| a.1.total_cmp(b.1))?;
+    let tied = values.iter().filter(|&&v| v == max).count() > 1;
+    (max > 0.0 && !tied).then_some(best)
+}
+
+/// Good paragraph text with whitespace collapsed, one paragraph per line.
+fn normalized_content(paragraphs: &[Paragraph]) -> String {
+    paragraphs
+        .iter()
+        .filter(|p| !p.is_boilerplate())
+        .map(|p| p.text.split_whitespace().collect::<Vec<_>>().join(" "))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// 64-bit FNV-1a.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+    bytes.iter().fold(OFFSET_BASIS, |hash, &b| {
+        (hash ^ u64::from(b)).wrapping_mul(PRIME)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare_xpaths() {
+        let mut xpaths = vec![
+            "/html[1]/body[1]/div[10]/p[1]",
+            "/html[1]/body[1]/div[2]/p[1]#2",
+            "/html[1]/body[1]/div[2]/p[1]",
+            "/html[1]/body[1]/div[2]",
+        ];
+        xpaths.sort_by(|a, b| compare_xpaths(a, b));
+        assert_eq!(
+            xpaths,
+            [
+                "/html[1]/body[1]/div[2]",
+                "/html[1]/body[1]/div[2]/p[1]",
+                "/html[1]/body[1]/div[2]/p[1]#2",
+                "/html[1]/body[1]/div[10]/p[1]",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_clear_best() {
+        assert_eq!(clear_best(&[0.1, 0.4, 0.2]), Some(1));
+        assert_eq!(clear_best(&[0.3, 0.3]), None);
+        assert_eq!(clear_best(&[0.0, 0.0]), None);
+        assert_eq!(clear_best(&[]), None);
+    }
+
+    #[test]
+    fn test_nearest_rank() {
+        let values = [10, 20, 30, 40];
+        assert_eq!(nearest_rank(&values, 0.0), Some(10));
+        assert_eq!(nearest_rank(&values, 0.5), Some(20));
+        assert_eq!(nearest_rank(&values, 0.51), Some(30));
+        assert_eq!(nearest_rank(&values, 1.0), Some(40));
+        assert_eq!(nearest_rank(&values, 7.0), Some(40));
+        assert_eq!(nearest_rank(&[5], 0.5), Some(5));
+        assert_eq!(nearest_rank(&[], 0.5), None);
+    }
+
+    #[test]
+    fn test_fnv1a_reference_values() {
+        // Reference vectors from the FNV specification.
+        assert_eq!(fnv1a(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a(b"a"), 0xaf63_dc4c_8601_ec8c);
+        assert_eq!(fnv1a(b"foobar"), 0x8594_4171_f739_67e8);
+    }
+}
@@ -25,24 +25,178 @@
 //! - [`html2markdown`](https://crates.io/crates/html2markdown) — converts HTML to
 //!   Markdown via an intermediate AST.
 
+#[macro_use]
+mod logging;
+
+mod analysis;
+mod article;
 mod classify;
+mod encoding;
+mod entities;
 mod error;
+mod evaluate;
+mod extract;
+mod metadata;
+mod output;
 mod paragraph;
 mod paragraph_maker;
 mod preprocess;
 mod revise;
+mod source;
 pub mod stoplists;
+mod text;
+#[cfg(feature = "wasm")]
+mod wasm;
 
-pub use error::JustextError;
-pub use paragraph::{ClassType, Paragraph};
-pub use stoplists::{available_languages, get_all_stoplists, get_stoplist};
+pub use analysis::{
+    boilerplate_path_histogram, document_score, group_by_container, main_content_block,
+    with_positions,
+};
+pub use article::{extract_article, extract_sections, Article, Section};
+pub use encoding::decode_html_bytes;
+pub use entities::{decoded_entities, DecodedEntity, EntityHandling};
+pub use error::{ConfigError, JustextError};
+pub use evaluate::{evaluate, Metrics};
+pub use extract::{
+    annotate_html, content_hash, extract_above_fold, extract_by_language, extract_by_percentile,
+    extract_canonical, extract_capped, extract_filtered, extract_html, extract_html_with_ranges,
+    extract_if_changed, extract_markdown, extract_sorted_by_xpath, extract_with_stats,
+    justext_with_inner_html, ExtractionStats, UNKNOWN_LANGUAGE,
+};
+#[cfg(feature = "jsonld")]
+pub use metadata::extract_jsonld_article;
+pub use metadata::extract_lead_image;
+pub use output::{to_csv, to_debug_html, to_ndjson};
+pub use paragraph::{ClassReason, ClassType, ClassificationReason, Paragraph, ParagraphBuilder};
+pub use paragraph_maker::default_paragraph_tags;
+pub use preprocess::{clean_html, visible_text, visible_text_with};
+pub use revise::apply_override;
+pub use stoplists::{
+    available_languages, copyright_markers, detect_language, get_all_stoplists,
+    get_merged_stoplist, get_stoplist, get_stoplist_set, register_stoplist, Language,
+};
+pub use text::extract_sentences;
+#[cfg(feature = "wasm")]
+pub use wasm::classify_paragraphs_json;
 
+use std::borrow::Cow;
 use std::collections::HashSet;
 
+/// Default [`Config::consent_phrases`]: wording typical of cookie/consent banners.
+pub const DEFAULT_CONSENT_PHRASES: &[&str] = &[
+    "we use cookies",
+    "this site uses cookies",
+    "accept all",
+    "reject all",
+    "accept cookies",
+    "manage preferences",
+    "cookie settings",
+    "cookie policy",
+    "privacy policy",
+    "your consent",
+];
+
+/// Unit in which [`Config::max_heading_distance`] is measured.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum DistanceUnit {
+    /// Length of the intervening paragraphs' text, measured by [`Config::length_metric`]
+    /// (characters by default, the Python jusText behavior).
+    #[default]
+    Chars,
+    /// Number of intervening paragraphs.
+    Paragraphs,
+}
+
+/// How paragraph text length is measured for `length_low`, `length_high`, and
+/// character-based heading distance.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[non_exhaustive]
+pub enum LengthMetric {
+    /// Unicode code points, matching Python's `len()` (jusText behavior).
+    #[default]
+    Chars,
+    /// UTF-8 bytes.
+    Bytes,
+    /// Extended grapheme clusters, so a base letter with combining marks counts once.
+    /// Requires the `unicode-segmentation` feature.
+    #[cfg(feature = "unicode-segmentation")]
+    Graphemes,
+}
+
+impl LengthMetric {
+    /// Length of `text` in this metric.
+    pub fn measure(&self, text: &str) -> usize {
+        match self {
+            LengthMetric::Chars => text.chars().count(),
+            LengthMetric::Bytes => text.len(),
+            #[cfg(feature = "unicode-segmentation")]
+            LengthMetric::Graphemes => {
+                unicode_segmentation::UnicodeSegmentation::graphemes(text, true).count()
+            }
+        }
+    }
+}
+
+/// How paragraph text is split into words for `Paragraph::words_count` and stopword
+/// matching; see [`Config::word_segmentation`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[non_exhaustive]
+pub enum WordSegmentation {
+    /// Split on whitespace, as Python jusText does.
+    #[default]
+    Whitespace,
+    /// Unicode (UAX #29) word boundaries, so unspaced Chinese or Japanese text counts one
+    /// word per ideograph instead of one per sentence. Punctuation isn't part of any word.
+    /// Requires the `unicode-segmentation` feature.
+    #[cfg(feature = "unicode-segmentation")]
+    UnicodeWords,
+}
+
+impl WordSegmentation {
+    /// The words of `text` under this segmentation.
+    pub fn split<'a>(&self, text: &'a str) -> Vec<&'a str> {
+        match self {
+            WordSegmentation::Whitespace => text.split_whitespace().collect(),
+            #[cfg(feature = "unicode-segmentation")]
+            WordSegmentation::UnicodeWords => {
+                unicode_segmentation::UnicodeSegmentation::unicode_words(text).collect()
+            }
+        }
+    }
+}
+
+/// Whether stopword matching also compares Snowball stems; see
+/// [`Config::stem_for_matching`]. Requires the `rust-stemmers` feature.
+#[cfg(feature = "rust-stemmers")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum StemMatching {
+    /// Exact (lowercased) words only, as in Python jusText.
+    #[default]
+    Off,
+    /// A word also matches when its stem is in the stoplist.
+    Tokens,
+    /// A word also matches when its stem equals the stem of a stoplist word.
+    TokensAndStoplist,
+}
+
 /// Configuration for the JusText algorithm.
 ///
 /// Defaults match Python JusText 3.0.2.
+///
+/// With the `serde` feature, serializes with the field names as keys; missing keys
+/// take their [`Config::default`] values, and unknown keys are rejected. See
+/// [`Config::from_json`].
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Config {
     pub length_low: usize,
@@ -52,6 +206,150 @@ pub struct Config {
     pub max_link_density: f64,
     pub max_heading_distance: usize,
     pub no_headings: bool,
+    /// Split block-less documents (plain text directly in `<body>`) into paragraphs at
+    /// blank lines. Documents containing any paragraph-level tag are unaffected.
+    pub split_plain_text_on_blank_lines: bool,
+    /// Force paragraphs inside `navigation`, `banner`, or `contentinfo` ARIA landmarks to Bad.
+    pub drop_landmark_boilerplate: bool,
+    /// After revision, demote everything following the first `©`/`&copy` paragraph to Bad,
+    /// treating it as the start of the footer.
+    ///
+    /// A copyright notice inside the article body (e.g. an image credit) triggers this
+    /// too and drops the rest of the article, so only enable it for sites whose
+    /// copyright line reliably starts the footer.
+    pub trim_after_copyright: bool,
+    /// Classify Bad any paragraph whose uppercase-to-letter ratio exceeds this value
+    /// (e.g. all-caps promo banners). Only applies to paragraphs of at least
+    /// `length_low` characters, so short acronym-heavy text isn't affected.
+    pub max_uppercase_ratio: Option<f64>,
+    /// Skip neighbor-based revision: `class_type` is left equal to `initial_class`.
+    /// Opt-in post-passes such as `trim_after_copyright` still run.
+    pub skip_revision: bool,
+    /// Whether `max_heading_distance` counts characters (default) or paragraphs.
+    pub heading_distance_unit: DistanceUnit,
+    /// After classification, remove a paragraph's last sentence or line when it equals one of
+    /// these phrases (case-insensitive, trailing punctuation ignored), e.g. `"Read more"`.
+    /// Applied by the extraction functions such as [`justext`], not by
+    /// [`classify_and_revise`], which leaves paragraph text alone.
+    pub strip_trailing_phrases: Vec<String>,
+    /// Classify Bad, regardless of length, any paragraph whose share of alphabetic characters
+    /// among non-whitespace characters is below this value (emoji reaction bars, rating
+    /// widgets). Number-heavy paragraphs such as score tables are affected too.
+    pub min_alpha_ratio: Option<f64>,
+    /// Treat consecutive headings (a title stacked above a subtitle) as one group: when
+    /// good content follows the stack within `max_heading_distance`, promote every heading in it.
+    pub promote_heading_stacks: bool,
+    /// How readily revision resolves NearGood paragraphs to Good, in `[0.0, 1.0]`.
+    ///
+    /// A NearGood paragraph becomes Good when its count of Good neighbours (0–2, looking
+    /// past Short and NearGood ones) is at least `2 * (1 - neargood_bias)`. The default
+    /// `0.5` needs one Good neighbour, as in Python jusText; `0.0` needs both, and `1.0`
+    /// resolves every NearGood paragraph to Good.
+    pub neargood_bias: f64,
+    /// Inline tags (case-insensitive, e.g. `"wbr"`, `"time"`) that don't count toward
+    /// `Paragraph::tags_count`.
+    pub ignored_inline_tags: Vec<String>,
+    /// Make a single `<br>` insert a newline instead of a space, preserving line structure
+    /// (addresses, poems). Two consecutive `<br>`s still end the paragraph.
+    pub br_as_newline: bool,
+    /// Keep superscripts and subscripts visible in the text: `x<sup>2</sup>` becomes
+    /// `x^(2)` and `H<sub>2</sub>O` becomes `H_(2)O`.
+    pub preserve_scripts: bool,
+    /// Demote cookie/consent banner paragraphs to Bad after revision: those containing two
+    /// or more `consent_phrases`, or one while a neighbouring paragraph contains one too.
+    pub drop_consent_banners: bool,
+    /// Phrases (case-insensitive) used by `drop_consent_banners`; defaults to
+    /// [`DEFAULT_CONSENT_PHRASES`].
+    pub consent_phrases: Vec<String>,
+    /// Pair each `<details>` widget's `<summary>` (the question) with its body (the answer)
+    /// in a single paragraph instead of fragmenting them. Collapsed widgets are extracted
+    /// too, since their content is in the DOM regardless of the `open` attribute.
+    pub expand_details: bool,
+    /// After revision, reassign each paragraph whose `initial_class` was Short by a majority
+    /// vote of the final classes of the `n` paragraphs on each side; ties go to Bad.
+    /// `None` (the default) and `Some(0)` leave revision's result untouched.
+    pub smoothing_window: Option<usize>,
+    /// Class/id patterns (case-insensitive) that mark boilerplate containers, e.g. `"sidebar"`
+    /// or `"ad-*"`. Each pattern is matched against every `class` token and the `id` of an
+    /// element: patterns with `*` are globs over the whole token, others match as
+    /// substrings. Paragraphs under a matching element are forced to Bad.
+    pub blocklist_patterns: Vec<String>,
+    /// Flag short byline/timestamp paragraphs ("By Jane Doe · March 3, 2024 · 5 min read")
+    /// as `Paragraph::is_metadata`: at most 20 words, a byline marker (a leading "By",
+    /// a `·`/`|`/`•` separator, or "min read", "updated", "published", "posted"),
+    /// and at least a quarter of the words being dates or numbers.
+    pub detect_metadata_lines: bool,
+    /// Force paragraphs flagged by `detect_metadata_lines` to Bad.
+    pub drop_metadata_lines: bool,
+    /// Stop the DOM walk once this many paragraphs have been produced, ignoring the rest
+    /// of the document. Bounds work and memory on untrusted input; `None` is unlimited.
+    pub max_paragraphs: Option<usize>,
+    /// Character (e.g. `' '`) written in place of each element the preprocessor removes
+    /// (scripts, form controls, embedded content), so `Click<button>..</button>here` doesn't
+    /// become `Clickhere`. Elements that are kept, including `ignored_inline_tags`, are
+    /// flattened as usual. `None` leaves removed elements without a trace.
+    pub inline_removal_placeholder: Option<char>,
+    /// After revision, force the longest (by words) paragraph whose `initial_class` was
+    /// Good or NearGood to Good, so a page never loses its main content block to noisy
+    /// neighbours. Ties pick the earliest paragraph.
+    pub anchor_largest_good: bool,
+    /// Keep each paragraph's normalized text-node fragments in `Paragraph::text_nodes`,
+    /// to debug how inline elements were flattened. Off by default to save memory.
+    pub keep_text_nodes: bool,
+    /// Tags (case-insensitive) that start and end a paragraph, replacing the built-in set.
+    /// To extend it instead, start from [`default_paragraph_tags`] and insert custom
+    /// tags such as `"article-body"`. `None` uses the built-in set.
+    pub paragraph_tags: Option<HashSet<String>>,
+    /// Force paragraphs flagged `Paragraph::interactive` (under an element with an event-handler
+    /// attribute such as `onclick`) to Bad. The flag itself is always set.
+    pub drop_interactive: bool,
+    /// Unit for paragraph length in the `length_low`/`length_high` checks and for
+    /// `DistanceUnit::Chars` heading distance. Defaults to characters, as in Python jusText.
+    pub length_metric: LengthMetric,
+    /// Extra substrings that mark a copyright notice, on top of `©` and a literal `&copy`.
+    /// Functions that take a language name (e.g. [`justext_lang`]) append that language's
+    /// [`copyright_markers`] for the call, so Chinese pages catch "版权所有".
+    pub copyright_markers: Vec<String>,
+    /// Set `Paragraph::is_probably_title` on the paragraph most likely to be the page title:
+    /// the first Good heading above the first Good body paragraph, preferring one that
+    /// matches the document's `<title>` or `og:title`. At most one paragraph is flagged
+    /// ([`classify_articles`] flags at most one per article).
+    pub detect_title: bool,
+    /// Keep the literal whitespace (indentation, line breaks) of text inside `<pre>` instead of
+    /// collapsing it, e.g. for code samples. Only blank lines at the start and trailing
+    /// whitespace at the end of such a paragraph are trimmed.
+    pub preserve_pre_whitespace: bool,
+    /// In [`extract_markdown`], render tables without a header row (no `<th>` cells or `<thead>`
+    /// in their first row) as plain paragraphs, as layout tables usually are, instead of as
+    /// Markdown tables with a blank header.
+    pub markdown_skip_headerless_tables: bool,
+    /// Match stopwords through Snowball stems as well, so inflected forms ("Häuser" for a
+    /// stoplist with "Haus") count. Uses the stemmer of `stemming_language`. Requires the
+    /// `rust-stemmers` feature.
+    #[cfg(feature = "rust-stemmers")]
+    pub stem_for_matching: StemMatching,
+    /// Language whose stemmer `stem_for_matching` uses (e.g. `"German"`). Functions that take
+    /// a language name fill it in when unset. Stemming is skipped for languages without a
+    /// Snowball stemmer. Requires the `rust-stemmers` feature.
+    #[cfg(feature = "rust-stemmers")]
+    pub stemming_language: Option<String>,
+    /// How text is split into words for `Paragraph::words_count` and stopword density.
+    /// Classification recounts `words_count` under any mode other than the default
+    /// whitespace splitting.
+    pub word_segmentation: WordSegmentation,
+    /// Scale the length and stopword thresholds by the paragraph's position: stricter (up to
+    /// 1.25×) in the first and last `position_edge_fraction` of the document, where navigation
+    /// and footers sit, and looser (down to 0.9×) towards the middle. Off by default, which
+    /// keeps the thresholds uniform.
+    pub position_weighting: bool,
+    /// Share of paragraphs at each end of the document that `position_weighting` treats as
+    /// the edge, in `[0.0, 0.5]`.
+    pub position_edge_fraction: f64,
+    /// Classify Bad every paragraph containing a copyright marker (`©`, `&copy`, or one of
+    /// `copyright_markers`), as Python jusText does. Turn off for content that discusses
+    /// copyright, so such paragraphs go through the normal length and density rules;
+    /// `trim_after_copyright` is unaffected.
+    pub drop_copyright_paragraphs: bool,
 }
 
 impl Default for Config {
@@ -64,6 +362,48 @@ impl Default for Config {
             max_link_density: 0.2,
             max_heading_distance: 200,
             no_headings: false,
+            split_plain_text_on_blank_lines: false,
+            drop_landmark_boilerplate: false,
+            trim_after_copyright: false,
+            max_uppercase_ratio: None,
+            skip_revision: false,
+            heading_distance_unit: DistanceUnit::Chars,
+            strip_trailing_phrases: Vec::new(),
+            min_alpha_ratio: None,
+            promote_heading_stacks: false,
+            neargood_bias: 0.5,
+            ignored_inline_tags: Vec::new(),
+            br_as_newline: false,
+            preserve_scripts: false,
+            drop_consent_banners: false,
+            consent_phrases: DEFAULT_CONSENT_PHRASES
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+            expand_details: false,
+            smoothing_window: None,
+            blocklist_patterns: Vec::new(),
+            detect_metadata_lines: false,
+            drop_metadata_lines: false,
+            max_paragraphs: None,
+            inline_removal_placeholder: None,
+            anchor_largest_good: false,
+            keep_text_nodes: false,
+            paragraph_tags: None,
+            drop_interactive: false,
+            length_metric: LengthMetric::Chars,
+            copyright_markers: Vec::new(),
+            detect_title: false,
+            preserve_pre_whitespace: false,
+            markdown_skip_headerless_tables: false,
+            #[cfg(feature = "rust-stemmers")]
+            stem_for_matching: StemMatching::Off,
+            #[cfg(feature = "rust-stemmers")]
+            stemming_language: None,
+            word_segmentation: WordSegmentation::Whitespace,
+            position_weighting: false,
+            position_edge_fraction: 0.2,
+            drop_copyright_paragraphs: true,
         }
     }
 }
@@ -97,30 +437,631 @@ impl Config {
         self.no_headings = v;
         self
     }
+    pub fn with_split_plain_text_on_blank_lines(mut self, v: bool) -> Self {
+        self.split_plain_text_on_blank_lines = v;
+        self
+    }
+    pub fn with_drop_landmark_boilerplate(mut self, v: bool) -> Self {
+        self.drop_landmark_boilerplate = v;
+        self
+    }
+    pub fn with_trim_after_copyright(mut self, v: bool) -> Self {
+        self.trim_after_copyright = v;
+        self
+    }
+    pub fn with_max_uppercase_ratio(mut self, v: Option<f64>) -> Self {
+        self.max_uppercase_ratio = v;
+        self
+    }
+    pub fn with_skip_revision(mut self, v: bool) -> Self {
+        self.skip_revision = v;
+        self
+    }
+    pub fn with_heading_distance_unit(mut self, v: DistanceUnit) -> Self {
+        self.heading_distance_unit = v;
+        self
+    }
+    pub fn with_strip_trailing_phrases(mut self, v: Vec<String>) -> Self {
+        self.strip_trailing_phrases = v;
+        self
+    }
+    pub fn with_min_alpha_ratio(mut self, v: Option<f64>) -> Self {
+        self.min_alpha_ratio = v;
+        self
+    }
+    pub fn with_promote_heading_stacks(mut self, v: bool) -> Self {
+        self.promote_heading_stacks = v;
+        self
+    }
+    pub fn with_neargood_bias(mut self, v: f64) -> Self {
+        self.neargood_bias = v;
+        self
+    }
+    pub fn with_ignored_inline_tags(mut self, v: Vec<String>) -> Self {
+        self.ignored_inline_tags = v;
+        self
+    }
+    pub fn with_br_as_newline(mut self, v: bool) -> Self {
+        self.br_as_newline = v;
+        self
+    }
+    pub fn with_preserve_scripts(mut self, v: bool) -> Self {
+        self.preserve_scripts = v;
+        self
+    }
+    pub fn with_drop_consent_banners(mut self, v: bool) -> Self {
+        self.drop_consent_banners = v;
+        self
+    }
+    pub fn with_consent_phrases(mut self, v: Vec<String>) -> Self {
+        self.consent_phrases = v;
+        self
+    }
+    pub fn with_expand_details(mut self, v: bool) -> Self {
+        self.expand_details = v;
+        self
+    }
+    pub fn with_smoothing_window(mut self, v: Option<usize>) -> Self {
+        self.smoothing_window = v;
+        self
+    }
+    pub fn with_blocklist_patterns(mut self, v: Vec<String>) -> Self {
+        self.blocklist_patterns = v;
+        self
+    }
+    pub fn with_detect_metadata_lines(mut self, v: bool) -> Self {
+        self.detect_metadata_lines = v;
+        self
+    }
+    pub fn with_drop_metadata_lines(mut self, v: bool) -> Self {
+        self.drop_metadata_lines = v;
+        self
+    }
+    pub fn with_max_paragraphs(mut self, v: Option<usize>) -> Self {
+        self.max_paragraphs = v;
+        self
+    }
+    pub fn with_inline_removal_placeholder(mut self, v: Option<char>) -> Self {
+        self.inline_removal_placeholder = v;
+        self
+    }
+    pub fn with_anchor_largest_good(mut self, v: bool) -> Self {
+        self.anchor_largest_good = v;
+        self
+    }
+    pub fn with_keep_text_nodes(mut self, v: bool) -> Self {
+        self.keep_text_nodes = v;
+        self
+    }
+    pub fn with_paragraph_tags(mut self, v: Option<HashSet<String>>) -> Self {
+        self.paragraph_tags = v;
+        self
+    }
+    pub fn with_drop_interactive(mut self, v: bool) -> Self {
+        self.drop_interactive = v;
+        self
+    }
+    pub fn with_length_metric(mut self, v: LengthMetric) -> Self {
+        self.length_metric = v;
+        self
+    }
+    pub fn with_copyright_markers(mut self, v: Vec<String>) -> Self {
+        self.copyright_markers = v;
+        self
+    }
+    pub fn with_detect_title(mut self, v: bool) -> Self {
+        self.detect_title = v;
+        self
+    }
+    pub fn with_preserve_pre_whitespace(mut self, v: bool) -> Self {
+        self.preserve_pre_whitespace = v;
+        self
+    }
+    pub fn with_markdown_skip_headerless_tables(mut self, v: bool) -> Self {
+        self.markdown_skip_headerless_tables = v;
+        self
+    }
+    #[cfg(feature = "rust-stemmers")]
+    pub fn with_stem_for_matching(mut self, v: StemMatching) -> Self {
+        self.stem_for_matching = v;
+        self
+    }
+    #[cfg(feature = "rust-stemmers")]
+    pub fn with_stemming_language(mut self, v: Option<String>) -> Self {
+        self.stemming_language = v;
+        self
+    }
+    pub fn with_word_segmentation(mut self, v: WordSegmentation) -> Self {
+        self.word_segmentation = v;
+        self
+    }
+    pub fn with_position_weighting(mut self, v: bool) -> Self {
+        self.position_weighting = v;
+        self
+    }
+    pub fn with_position_edge_fraction(mut self, v: f64) -> Self {
+        self.position_edge_fraction = v;
+        self
+    }
+    pub fn with_drop_copyright_paragraphs(mut self, v: bool) -> Self {
+        self.drop_copyright_paragraphs = v;
+        self
+    }
+
+    /// Start building a validated config; see [`ConfigBuilder`].
+    pub fn builder() -> ConfigBuilder {
+        ConfigBuilder::default()
+    }
+
+    /// Check that the thresholds are consistent.
+    ///
+    /// `length_low` must not exceed `length_high`, nor `stopwords_low` exceed
+    /// `stopwords_high`, and the densities and ratios (`stopwords_low`, `stopwords_high`,
+    /// `max_link_density`, `neargood_bias`, `max_uppercase_ratio`, `min_alpha_ratio`)
+    /// must lie within `[0.0, 1.0]`, and `position_edge_fraction` within `[0.0, 0.5]`. The
+    /// `with_*` methods don't call this.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let ratios = [
+            ("stopwords_low", Some(self.stopwords_low)),
+            ("stopwords_high", Some(self.stopwords_high)),
+            ("max_link_density", Some(self.max_link_density)),
+            ("neargood_bias", Some(self.neargood_bias)),
+            ("max_uppercase_ratio", self.max_uppercase_ratio),
+            ("min_alpha_ratio", self.min_alpha_ratio),
+        ];
+        for (field, value) in ratios {
+            if let Some(value) = value.filter(|v| !(0.0..=1.0).contains(v)) {
+                return Err(ConfigError::OutOfRange { field, value });
+            }
+        }
+        if !(0.0..=0.5).contains(&self.position_edge_fraction) {
+            return Err(ConfigError::EdgeFraction {
+                value: self.position_edge_fraction,
+            });
+        }
+        if self.length_low > self.length_high {
+            return Err(ConfigError::LengthRange {
+                low: self.length_low,
+                high: self.length_high,
+            });
+        }
+        if self.stopwords_low > self.stopwords_high {
+            return Err(ConfigError::StopwordsRange {
+                low: self.stopwords_low,
+                high: self.stopwords_high,
+            });
+        }
+        Ok(())
+    }
+
+    /// Load a config from a JSON object, e.g. a tuning file kept outside the code.
+    ///
+    /// Keys are the field names; missing keys keep their [`Config::default`] values, so
+    /// `{"length_low": 50}` changes only `length_low`. Unknown keys and malformed JSON
+    /// are [`ConfigError::Json`], and the result is checked with
+    /// [`validate`](Self::validate). Other formats such as TOML work through the
+    /// `Deserialize` impl directly (then call `validate` yourself).
+    ///
+    /// ```rust
+    /// let config = justext::Config::from_json(r#"{"length_low": 50}"#).unwrap();
+    /// assert_eq!(config.length_low, 50);
+    /// assert_eq!(config.length_high, justext::Config::default().length_high);
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> Result<Config, ConfigError> {
+        let config: Config =
+            serde_json::from_str(json).map_err(|e| ConfigError::Json(e.to_string()))?;
+        config.validate()?;
+        Ok(config)
+    }
+}
+
+/// Builder for [`Config`] that validates the thresholds on [`build`](Self::build).
+///
+/// Setters cover the classifier thresholds; to validate other options too, start
+/// from a `with_*` chain via `ConfigBuilder::from(config)`.
+///
+/// ```rust
+/// let config = justext::Config::builder()
+///     .length_low(50)
+///     .stopwords_high(0.35)
+///     .build()
+///     .unwrap();
+/// assert_eq!(config.length_low, 50);
+///
+/// assert!(justext::Config::builder().max_link_density(1.5).build().is_err());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ConfigBuilder {
+    config: Config,
+}
+
+impl ConfigBuilder {
+    pub fn length_low(mut self, n: usize) -> Self {
+        self.config.length_low = n;
+        self
+    }
+    pub fn length_high(mut self, n: usize) -> Self {
+        self.config.length_high = n;
+        self
+    }
+    pub fn stopwords_low(mut self, v: f64) -> Self {
+        self.config.stopwords_low = v;
+        self
+    }
+    pub fn stopwords_high(mut self, v: f64) -> Self {
+        self.config.stopwords_high = v;
+        self
+    }
+    pub fn max_link_density(mut self, v: f64) -> Self {
+        self.config.max_link_density = v;
+        self
+    }
+    pub fn max_heading_distance(mut self, n: usize) -> Self {
+        self.config.max_heading_distance = n;
+        self
+    }
+    pub fn no_headings(mut self, v: bool) -> Self {
+        self.config.no_headings = v;
+        self
+    }
+    pub fn neargood_bias(mut self, v: f64) -> Self {
+        self.config.neargood_bias = v;
+        self
+    }
+    pub fn max_uppercase_ratio(mut self, v: Option<f64>) -> Self {
+        self.config.max_uppercase_ratio = v;
+        self
+    }
+    pub fn min_alpha_ratio(mut self, v: Option<f64>) -> Self {
+        self.config.min_alpha_ratio = v;
+        self
+    }
+
+    /// Validate and return the config; see [`Config::validate`].
+    pub fn build(self) -> Result<Config, ConfigError> {
+        self.config.validate()?;
+        Ok(self.config)
+    }
+}
+
+impl From<Config> for ConfigBuilder {
+    fn from(config: Config) -> Self {
+        Self { config }
+    }
 }
 
 /// Classify paragraphs in HTML as content or boilerplate.
 pub fn justext(html: &str, stoplist: &HashSet<String>, config: &Config) -> Vec<Paragraph> {
-    let doc = preprocess::preprocess(html);
-    let mut paragraphs = paragraph_maker::make_paragraphs(&doc);
-    classify::classify_paragraphs(&mut paragraphs, stoplist, config);
-    revise::revise_paragraph_classification(&mut paragraphs, config.max_heading_distance);
+    let doc = PreparedDocument::new(&scraper::Html::parse_document(html), config);
+    let mut source = source::SourceMap::new(html, config);
+    run_pipeline(&doc, None, Some(&mut source), stoplist, config)
+}
+
+/// Classify an already-parsed document, e.g. one also used for other scraping.
+///
+/// Same as [`justext`] except that `source_start`/`source_end` stay `0`, since the
+/// source text isn't available. `doc` must come from the same `scraper` version as
+/// this crate uses.
+pub fn justext_from_html(
+    doc: &scraper::Html,
+    stoplist: &HashSet<String>,
+    config: &Config,
+) -> Vec<Paragraph> {
+    classify_document(doc, stoplist, config).1
+}
+
+/// Preprocess and classify `doc`, returning the cleaned tree the paragraphs' xpaths
+/// refer to along with them.
+pub(crate) fn classify_document(
+    doc: &scraper::Html,
+    stoplist: &HashSet<String>,
+    config: &Config,
+) -> (scraper::Html, Vec<Paragraph>) {
+    let doc = PreparedDocument::new(doc, config);
+    let paragraphs = run_pipeline(&doc, None, None, stoplist, config);
+    (doc.cleaned, paragraphs)
+}
+
+/// A parsed page ready for segmentation: the cleaned tree and, when
+/// [`Config::detect_title`] is on, the title declared in the original `<head>` (which
+/// preprocessing removes).
+pub(crate) struct PreparedDocument {
+    pub(crate) cleaned: scraper::Html,
+    title: Option<String>,
+}
+
+impl PreparedDocument {
+    pub(crate) fn new(doc: &scraper::Html, config: &Config) -> Self {
+        Self {
+            cleaned: preprocess::preprocess_document(doc, config.inline_removal_placeholder),
+            title: config
+                .detect_title
+                .then(|| metadata::document_title(doc))
+                .flatten(),
+        }
+    }
+}
+
+/// The pipeline behind every entry point: segment `doc` (or only the subtrees under
+/// `roots`), fill in source offsets when a `source` map is given, then classify and
+/// revise each root's paragraphs on their own and concatenate them in document order.
+/// Title detection runs once over the combined result.
+///
+/// Offsets must be located before classification, since trailing-phrase stripping
+/// changes paragraph text.
+pub(crate) fn run_pipeline(
+    doc: &PreparedDocument,
+    roots: Option<&[ego_tree::NodeRef<scraper::Node>]>,
+    mut source: Option<&mut source::SourceMap>,
+    stoplist: &HashSet<String>,
+    config: &Config,
+) -> Vec<Paragraph> {
+    let mut classify = |mut paragraphs: Vec<Paragraph>| {
+        if let Some(source) = source.as_deref_mut() {
+            source.locate(&mut paragraphs);
+        }
+        classify_and_revise(&mut paragraphs, stoplist, config);
+        revise::strip_trailing_phrases(&mut paragraphs, config);
+        paragraphs
+    };
+    let mut paragraphs = match roots {
+        None => classify(paragraph_maker::make_paragraphs(&doc.cleaned, config)),
+        Some(roots) => roots
+            .iter()
+            .flat_map(|root| {
+                classify(paragraph_maker::make_paragraphs_in(
+                    &doc.cleaned,
+                    *root,
+                    config,
+                ))
+            })
+            .collect(),
+    };
+    if config.detect_title {
+        revise::mark_probable_title(&mut paragraphs, doc.title.as_deref());
+    }
+    paragraphs
+}
+
+/// Run the full pipeline and hand each classified paragraph to `f`, in document order.
+///
+/// Revision needs every paragraph at once, so the list is still built internally; this
+/// saves callers that serialize paragraphs as they go from collecting a `Vec` of their
+/// own. Classifications are identical to [`justext`].
+pub fn justext_for_each(
+    html: &str,
+    stoplist: &HashSet<String>,
+    config: &Config,
+    f: impl FnMut(&Paragraph),
+) {
+    justext(html, stoplist, config).iter().for_each(f);
+}
+
+/// Classify many documents in parallel on the rayon thread pool.
+///
+/// Each document runs through [`justext`] independently, sharing `stoplist` and
+/// `config`; results are in the same order as `htmls`. Requires the `rayon` feature.
+#[cfg(feature = "rayon")]
+pub fn justext_batch(
+    htmls: &[&str],
+    stoplist: &HashSet<String>,
+    config: &Config,
+) -> Vec<Vec<Paragraph>> {
+    use rayon::prelude::*;
+
+    htmls
+        .par_iter()
+        .map(|html| justext(html, stoplist, config))
+        .collect()
+}
+
+/// Classify a page delivered as several HTML fragments (e.g. header, body, footer).
+///
+/// The fragments are concatenated in order and parsed as one document; the HTML parser
+/// supplies the `<html>`/`<body>` wrapper when fragments lack one and ignores repeated
+/// wrappers when they have them. Revision therefore runs across fragment boundaries,
+/// and `xpath`s refer to the combined document.
+pub fn justext_fragments(
+    fragments: &[&str],
+    stoplist: &HashSet<String>,
+    config: &Config,
+) -> Vec<Paragraph> {
+    justext(&fragments.join("\n"), stoplist, config)
+}
+
+/// Classify each top-level `<article>` element separately.
+///
+/// Index and aggregator pages often hold several self-contained posts; running the
+/// pipeline per article keeps one post's navigation or comments from influencing
+/// another's revision. Articles nested inside another article belong to the outer one,
+/// and content outside every article is not returned. Paths are document-wide, as in
+/// [`justext`]. A page without `<article>` elements returns a single group holding the
+/// whole document's paragraphs.
+pub fn classify_articles(
+    html: &str,
+    stoplist: &HashSet<String>,
+    config: &Config,
+) -> Vec<Vec<Paragraph>> {
+    let doc = PreparedDocument::new(&scraper::Html::parse_document(html), config);
+    let sel = scraper::Selector::parse("article").unwrap();
+    let articles: Vec<_> = doc
+        .cleaned
+        .select(&sel)
+        .filter(|a| {
+            !a.ancestors()
+                .filter_map(scraper::ElementRef::wrap)
+                .any(|e| e.value().name() == "article")
+        })
+        .map(|a| *a)
+        .collect();
+    let mut source = source::SourceMap::new(html, config);
+    if articles.is_empty() {
+        return vec![run_pipeline(
+            &doc,
+            None,
+            Some(&mut source),
+            stoplist,
+            config,
+        )];
+    }
+    articles
+        .iter()
+        .map(|article| {
+            let roots = std::slice::from_ref(article);
+            run_pipeline(&doc, Some(roots), Some(&mut source), stoplist, config)
+        })
+        .collect()
+}
+
+/// Classify only the content inside elements matching a CSS `selector`.
+///
+/// For pages with a known content container (`main`, `article.post`), this keeps
+/// sidebars and navigation out of the result and out of revision. Each matching
+/// subtree is classified on its own, like an article in [`classify_articles`], and the
+/// results are concatenated in document order. Matches nested inside another match
+/// belong to the outer one. Paths and source offsets are document-wide, as in
+/// [`justext`]; no match yields an empty list.
+///
+/// The selector is matched against the cleaned document, so it can't target removed
+/// elements such as `<script>` or `<form>` contents. Returns
+/// [`JustextError::InvalidSelector`] if `scraper` can't parse it.
+pub fn justext_within(
+    html: &str,
+    selector: &str,
+    stoplist: &HashSet<String>,
+    config: &Config,
+) -> Result<Vec<Paragraph>, JustextError> {
+    let sel = scraper::Selector::parse(selector)
+        .map_err(|_| JustextError::InvalidSelector(selector.to_string()))?;
+    let doc = PreparedDocument::new(&scraper::Html::parse_document(html), config);
+    let matches: Vec<_> = doc.cleaned.select(&sel).collect();
+    let roots: Vec<_> = matches
+        .iter()
+        .filter(|root| {
+            !root
+                .ancestors()
+                .any(|a| matches.iter().any(|m| m.id() == a.id()))
+        })
+        .map(|root| **root)
+        .collect();
+    let mut source = source::SourceMap::new(html, config);
+    Ok(run_pipeline(
+        &doc,
+        Some(&roots),
+        Some(&mut source),
+        stoplist,
+        config,
+    ))
+}
+
+/// Classify and revise paragraphs produced by your own segmentation.
+///
+/// Runs the same context-free classification and neighbor-based revision as
+/// [`justext`], so paragraphs built with [`Paragraph::new`] get the classes the full
+/// pipeline would assign to equivalent paragraphs. Only classes and metrics change:
+/// [`Config::strip_trailing_phrases`] is applied by the extraction functions, not here.
+pub fn classify_and_revise(
+    paragraphs: &mut [Paragraph],
+    stoplist: &HashSet<String>,
+    config: &Config,
+) {
+    classify::classify_paragraphs(paragraphs, stoplist, config);
+    if config.skip_revision {
+        for p in paragraphs.iter_mut() {
+            p.class_type = p.initial_class;
+        }
+    } else {
+        revise::revise_paragraph_classification(paragraphs, config);
+        if config.promote_heading_stacks {
+            revise::promote_heading_stacks(paragraphs, config);
+        }
+        if let Some(window) = config.smoothing_window {
+            revise::smooth_short_paragraphs(paragraphs, window);
+        }
+    }
+    if config.anchor_largest_good {
+        revise::anchor_largest_good(paragraphs);
+    }
+    if config.trim_after_copyright {
+        revise::trim_after_copyright(paragraphs, &config.copyright_markers);
+    }
+    if config.drop_consent_banners {
+        revise::drop_consent_banners(paragraphs, &config.consent_phrases);
+    }
+}
+
+/// Re-run [`classify_and_revise`] in place and return the indices whose `class_type`
+/// changed, in ascending order.
+///
+/// Meant for interactive tools that re-classify after a config tweak and only want to
+/// repaint what changed. The paragraphs end in the same state as a fresh
+/// classification with `config`.
+pub fn reclassify_diff(
+    paragraphs: &mut [Paragraph],
+    stoplist: &HashSet<String>,
+    config: &Config,
+) -> Vec<usize> {
+    let before: Vec<ClassType> = paragraphs.iter().map(|p| p.class_type).collect();
+    classify_and_revise(paragraphs, stoplist, config);
     paragraphs
+        .iter()
+        .zip(before)
+        .enumerate()
+        .filter(|(_, (p, old))| p.class_type != *old)
+        .map(|(i, _)| i)
+        .collect()
 }
 
 /// Convenience: extract only the good paragraph text.
 pub fn extract_text(html: &str, stoplist: &HashSet<String>, config: &Config) -> String {
-    justext(html, stoplist, config)
-        .into_iter()
-        .filter(|p| !p.is_boilerplate())
+    good_paragraphs(html, stoplist, config)
         .map(|p| p.text)
         .collect::<Vec<_>>()
         .join("\n")
 }
 
+/// The good (non-boilerplate) paragraphs, yielded one at a time in document order.
+///
+/// Classification and revision still run over the whole document before the first
+/// item is produced; only the final filtering is lazy.
+pub fn good_paragraphs(
+    html: &str,
+    stoplist: &HashSet<String>,
+    config: &Config,
+) -> impl Iterator<Item = Paragraph> {
+    justext(html, stoplist, config)
+        .into_iter()
+        .filter(|p| !p.is_boilerplate())
+}
+
+/// Resolve a language name to its stoplist, and `config` with the language's
+/// [`copyright_markers`] appended (and, with stemming on, `stemming_language` filled
+/// in), for the functions that take a language name.
+pub(crate) fn resolve_language<'a>(
+    language: &str,
+    config: &'a Config,
+) -> Result<(HashSet<String>, Cow<'a, Config>), JustextError> {
+    let stoplist = get_stoplist(language)?;
+    let mut config = Cow::Borrowed(config);
+    let markers = copyright_markers(language);
+    if !markers.is_empty() {
+        config
+            .to_mut()
+            .copyright_markers
+            .extend(markers.iter().map(|m| m.to_string()));
+    }
+    #[cfg(feature = "rust-stemmers")]
+    if config.stem_for_matching != StemMatching::Off && config.stemming_language.is_none() {
+        config.to_mut().stemming_language = Some(language.to_string());
+    }
+    Ok((stoplist, config))
+}
+
 /// Classify paragraphs using a language name instead of a pre-loaded stoplist.
 ///
-/// Equivalent to `get_stoplist(language)` followed by `justext()`.
+/// Equivalent to `get_stoplist(language)` followed by `justext()`, with the
+/// language's [`copyright_markers`] added to the config.
 ///
 /// # Example
 ///
@@ -132,13 +1073,43 @@ pub fn justext_lang(
     language: &str,
     config: &Config,
 ) -> Result<Vec<Paragraph>, JustextError> {
-    let stoplist = get_stoplist(language)?;
-    Ok(justext(html, &stoplist, config))
+    let (stoplist, config) = resolve_language(language, config)?;
+    Ok(justext_lang_with(html, &stoplist, &config))
+}
+
+/// [`justext_lang`] for a [`Language`], which can't be unknown.
+pub fn justext_language(html: &str, language: Language, config: &Config) -> Vec<Paragraph> {
+    let (stoplist, config) =
+        resolve_language(language.as_str(), config).expect("embedded languages always resolve");
+    justext_lang_with(html, &stoplist, &config)
+}
+
+/// Classify paragraphs with a borrowed, long-lived stoplist.
+///
+/// The no-lookup counterpart of [`justext_lang`]: load the stoplist once (e.g. with
+/// [`get_stoplist`] at startup) and pass it by reference on every call, so the hot
+/// path performs no stoplist allocation. Identical to [`justext`]; unlike
+/// [`justext_lang`], nothing is derived from a language name, so add a language's
+/// [`copyright_markers`] (or `stemming_language`) to `config` yourself if wanted.
+///
+/// # Example
+///
+/// ```rust
+/// let english = justext::get_stoplist("English").unwrap();
+/// let paragraphs = justext::justext_lang_with("<html><body><p>Hello world</p></body></html>", &english, &justext::Config::default());
+/// ```
+pub fn justext_lang_with(
+    html: &str,
+    stoplist: &HashSet<String>,
+    config: &Config,
+) -> Vec<Paragraph> {
+    justext(html, stoplist, config)
 }
 
 /// Extract only the good paragraph text using a language name.
 ///
-/// Equivalent to `get_stoplist(language)` followed by `extract_text()`.
+/// Equivalent to `get_stoplist(language)` followed by `extract_text()`, with the
+/// language's [`copyright_markers`] added to the config.
 ///
 /// # Example
 ///
@@ -150,6 +1121,118 @@ pub fn extract_text_lang(
     language: &str,
     config: &Config,
 ) -> Result<String, JustextError> {
-    let stoplist = get_stoplist(language)?;
-    Ok(extract_text(html, &stoplist, config))
+    let (stoplist, config) = resolve_language(language, config)?;
+    Ok(extract_text(html, &stoplist, &config))
+}
+
+/// [`extract_text_lang`] for a [`Language`], which can't be unknown.
+pub fn extract_text_language(html: &str, language: Language, config: &Config) -> String {
+    let (stoplist, config) =
+        resolve_language(language.as_str(), config).expect("embedded languages always resolve");
+    extract_text(html, &stoplist, &config)
+}
+
+/// Extract good text from raw HTML bytes of any charset, using a language name.
+///
+/// The charset comes from a byte order mark or a `<meta>` declaration, falling back to
+/// UTF-8 when the bytes are valid UTF-8 and Windows-1252 otherwise; the decoded text
+/// then goes through [`extract_text_lang`]. Requires the `encoding` feature.
+#[cfg(feature = "encoding")]
+pub fn extract_text_from_bytes(
+    bytes: &[u8],
+    language: &str,
+    config: &Config,
+) -> Result<String, JustextError> {
+    extract_text_lang(
+        &encoding::decode_html_bytes_sniffed(bytes),
+        language,
+        config,
+    )
+}
+
+/// Extract good text without knowing the page language.
+///
+/// The language is guessed with [`detect_language`] and its stoplist used as in
+/// [`extract_text_lang`]. When detection is inconclusive, the merged stoplist of every
+/// language ([`get_all_stoplists`]) is used instead.
+pub fn extract_text_auto(html: &str, config: &Config) -> String {
+    match detect_language(html).and_then(|language| resolve_language(language, config).ok()) {
+        Some((stoplist, config)) => extract_text(html, &stoplist, &config),
+        None => extract_text(html, get_all_stoplists(), config),
+    }
+}
+
+/// Extract good text from many `(name, html)` documents with one language and config.
+///
+/// The stoplist is resolved once, before any document is processed, so an unknown
+/// language fails up front. Results keep the input order, paired with their names.
+pub fn extract_all<'a, I>(
+    items: I,
+    language: &str,
+    config: &Config,
+) -> Result<Vec<(String, String)>, JustextError>
+where
+    I: IntoIterator<Item = (&'a str, &'a str)>,
+{
+    let (stoplist, config) = resolve_language(language, config)?;
+    Ok(items
+        .into_iter()
+        .map(|(name, html)| (name.to_string(), extract_text(html, &stoplist, &config)))
+        .collect())
+}
+
+/// Like [`extract_text_lang`], but returns `None` when the page has too little content.
+///
+/// The threshold is compared against the summed `words_count` of the good paragraphs,
+/// the classifier's `words_count`.
+pub fn extract_text_if_substantial(
+    html: &str,
+    language: &str,
+    config: &Config,
+    min_words: usize,
+) -> Result<Option<String>, JustextError> {
+    let (stoplist, config) = resolve_language(language, config)?;
+    let good: Vec<Paragraph> = justext(html, &stoplist, &config)
+        .into_iter()
+        .filter(|p| !p.is_boilerplate())
+        .collect();
+    if good.iter().map(|p| p.words_count).sum::<usize>() < min_words {
+        return Ok(None);
+    }
+    Ok(Some(
+        good.into_iter()
+            .map(|p| p.text)
+            .collect::<Vec<_>>()
+            .join("\n"),
+    ))
+}
+
+/// Like [`extract_text_lang`], but returns `None` when too few paragraphs are good.
+///
+/// The ratio is by paragraph count: good paragraphs over all paragraphs, so a page
+/// that is mostly navigation, a wrong-language page, or a JavaScript shell falls below
+/// `min_good_ratio`. A page with no paragraphs has a ratio of `0.0`.
+pub fn extract_text_gated(
+    html: &str,
+    language: &str,
+    config: &Config,
+    min_good_ratio: f64,
+) -> Result<Option<String>, JustextError> {
+    let (stoplist, config) = resolve_language(language, config)?;
+    let paragraphs = justext(html, &stoplist, &config);
+    let total = paragraphs.len();
+    let good: Vec<String> = paragraphs
+        .into_iter()
+        .filter(|p| !p.is_boilerplate())
+        .map(|p| p.text)
+        .collect();
+    let ratio = if total == 0 {
+        0.0
+    } else {
+        good.len() as f64 / total as f64
+    };
+    if ratio < min_good_ratio {
+        return Ok(None);
+    }
+    Ok(Some(good.join("\n")))
 }
@@ -0,0 +1,22 @@
+// Optional classification logging through the `log` crate (feature `log`).
+//
+// Each macro checks `log_enabled!` before formatting, and expands to nothing when the
+// feature is off, so disabled logging costs nothing.
+
+macro_rules! debug_log {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "log")]
+        if log::log_enabled!(log::Level::Debug) {
+            log::debug!($($arg)*);
+        }
+    };
+}
+
+macro_rules! trace_log {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "log")]
+        if log::log_enabled!(log::Level::Trace) {
+            log::trace!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,220 @@
+// Page metadata read from the raw HTML, before preprocessing strips <head>, <img>, etc.
+
+use scraper::{ElementRef, Html, Selector};
+
+/// Minimum declared `width`/`height` for an `<img>` to count as a lead image.
+///
+/// Filters out tracking pixels and spacer GIFs, which are typically 1x1.
+const MIN_LEAD_IMAGE_SIZE: u32 = 50;
+
+/// Return the URL of the page's lead image.
+///
+/// Prefers the publisher-declared `<meta property="og:image">`. Otherwise returns the
+/// `src` of the first `<img>` whose declared `width` and `height` (when present) are
+/// at least 50 pixels, which skips tracking pixels. Images without size attributes
+/// are accepted.
+pub fn extract_lead_image(html: &str) -> Option<String> {
+    let doc = Html::parse_document(html);
+    og_image(&doc).or_else(|| first_content_image(&doc))
+}
+
+fn og_image(doc: &Html) -> Option<String> {
+    let sel = Selector::parse(r#"meta[property="og:image"]"#).unwrap();
+    doc.select(&sel)
+        .filter_map(|m| m.value().attr("content"))
+        .map(str::trim)
+        .find(|c| !c.is_empty())
+        .map(str::to_string)
+}
+
+fn first_content_image(doc: &Html) -> Option<String> {
+    let sel = Selector::parse("img[src]").unwrap();
+    doc.select(&sel)
+        .filter(|img| !is_tiny(img))
+        .filter_map(|img| img.value().attr("src"))
+        .map(str::trim)
+        .find(|src| !src.is_empty())
+        .map(str::to_string)
+}
+
+/// Returns true if either declared dimension is below [`MIN_LEAD_IMAGE_SIZE`].
+fn is_tiny(img: &ElementRef) -> bool {
+    ["width", "height"].iter().any(|attr| {
+        img.value()
+            .attr(attr)
+            .and_then(parse_pixels)
+            .is_some_and(|px| px < MIN_LEAD_IMAGE_SIZE)
+    })
+}
+
+/// Parse an HTML dimension attribute like `"1"` or `"300px"`.
+fn parse_pixels(value: &str) -> Option<u32> {
+    value.trim().trim_end_matches("px").trim().parse().ok()
+}
+
+/// The publisher-declared title: `og:title` if present, otherwise `<title>`, with
+/// whitespace collapsed.
+pub(crate) fn document_title(doc: &Html) -> Option<String> {
+    let og = Selector::parse(r#"meta[property="og:title"]"#).unwrap();
+    let title = Selector::parse("title").unwrap();
+    doc.select(&og)
+        .filter_map(|m| m.value().attr("content").map(str::to_string))
+        .chain(doc.select(&title).map(|el| el.text().collect()))
+        .map(|t| t.split_whitespace().collect::<Vec<_>>().join(" "))
+        .find(|t| !t.is_empty())
+}
+
+/// Schema.org types whose `articleBody`/`text` we treat as the article.
+#[cfg(feature = "jsonld")]
+const ARTICLE_TYPES: &[&str] = &[
+    "Article",
+    "NewsArticle",
+    "BlogPosting",
+    "ReportageNewsArticle",
+    "AnalysisNewsArticle",
+    "OpinionNewsArticle",
+    "TechArticle",
+    "ScholarlyArticle",
+];
+
+/// Return the article body declared in the page's JSON-LD metadata.
+///
+/// Scans every `<script type="application/ld+json">` block in the raw HTML (these are
+/// stripped before classification, so this reads the unprocessed markup) and returns
+/// the first non-empty `articleBody`. For nodes typed as an article (`Article`,
+/// `NewsArticle`, `BlogPosting`, ...) the schema.org `text` property is accepted as
+/// well. Top-level arrays, `@graph` lists, and nested objects are searched; blocks
+/// that aren't valid JSON are skipped.
+///
+/// Publishers usually put the complete body here, so it makes a high-quality fallback
+/// when paragraph classification comes up short.
+#[cfg(feature = "jsonld")]
+pub fn extract_jsonld_article(html: &str) -> Option<String> {
+    let doc = Html::parse_document(html);
+    let sel = Selector::parse(r#"script[type="application/ld+json"]"#).unwrap();
+    doc.select(&sel)
+        .filter_map(|script| serde_json::from_str(&script.text().collect::<String>()).ok())
+        .find_map(|value| find_article_body(&value))
+}
+
+#[cfg(feature = "jsonld")]
+fn find_article_body(value: &serde_json::Value) -> Option<String> {
+    use serde_json::Value;
+
+    match value {
+        Value::Array(items) => items.iter().find_map(find_article_body),
+        Value::Object(map) => {
+            let non_empty = |key: &str| {
+                map.get(key)
+                    .and_then(Value::as_str)
+                    .map(str::trim)
+                    .filter(|s| !s.is_empty())
+                    .map(str::to_string)
+            };
+            non_empty("articleBody")
+                .or_else(|| {
+                    is_article(map.get("@type"))
+                        .then(|| non_empty("text"))
+                        .flatten()
+                })
+                .or_else(|| map.values().find_map(find_article_body))
+        }
+        _ => None,
+    }
+}
+
+/// Returns true if a JSON-LD `@type` (a string or list of strings) names an article type.
+#[cfg(feature = "jsonld")]
+fn is_article(ty: Option<&serde_json::Value>) -> bool {
+    use serde_json::Value;
+
+    let matches = |t: &Value| t.as_str().is_some_and(|t| ARTICLE_TYPES.contains(&t));
+    match ty {
+        Some(Value::Array(types)) => types.iter().any(matches),
+        Some(t) => matches(t),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lead_image_skips_tracking_pixel() {
+        let html = concat!(
+            "<html><body>",
+            "<img src=\"/pixel.gif\" width=\"1\" height=\"1\">",
+            "<p>Intro</p>",
+            "<img src=\"/photo.jpg\" width=\"640\" height=\"480\">",
+            "</body></html>"
+        );
+        assert_eq!(extract_lead_image(html).as_deref(), Some("/photo.jpg"));
+    }
+
+    #[test]
+    fn test_lead_image_prefers_og_image() {
+        let html = concat!(
+            "<html><head><meta property=\"og:image\" content=\"https://x.test/og.png\"></head>",
+            "<body><img src=\"/photo.jpg\"></body></html>"
+        );
+        assert_eq!(
+            extract_lead_image(html).as_deref(),
+            Some("https://x.test/og.png")
+        );
+    }
+
+    #[test]
+    fn test_lead_image_accepts_unsized_image() {
+        let html = "<html><body><img src=\"/a.jpg\"></body></html>";
+        assert_eq!(extract_lead_image(html).as_deref(), Some("/a.jpg"));
+    }
+
+    #[test]
+    fn test_lead_image_none() {
+        let html = "<html><body><img src=\"/px.gif\" width=\"1px\"><p>text</p></body></html>";
+        assert_eq!(extract_lead_image(html), None);
+    }
+
+    #[cfg(feature = "jsonld")]
+    #[test]
+    fn test_jsonld_news_article() {
+        let html = concat!(
+            "<html><head>",
+            "<script type=\"application/ld+json\">{\"@context\":\"https://schema.org\",",
+            "\"@type\":\"BreadcrumbList\",\"itemListElement\":[]}</script>",
+            "<script type=\"application/ld+json\">{\"@context\":\"https://schema.org\",",
+            "\"@type\":\"NewsArticle\",\"headline\":\"Big news\",",
+            "\"articleBody\":\"The full story, as published.\"}</script>",
+            "</head><body><p>Teaser</p></body></html>"
+        );
+        assert_eq!(
+            extract_jsonld_article(html).as_deref(),
+            Some("The full story, as published.")
+        );
+    }
+
+    #[cfg(feature = "jsonld")]
+    #[test]
+    fn test_jsonld_graph_and_text_fallback() {
+        let html = concat!(
+            "<html><head>",
+            "<script type=\"application/ld+json\">not json</script>",
+            "<script type=\"application/ld+json\">{\"@graph\":[",
+            "{\"@type\":\"WebPage\",\"text\":\"Page chrome\"},",
+            "{\"@type\":[\"Article\"],\"text\":\"Body from text.\"}]}</script>",
+            "</head><body></body></html>"
+        );
+        assert_eq!(
+            extract_jsonld_article(html).as_deref(),
+            Some("Body from text.")
+        );
+    }
+
+    #[cfg(feature = "jsonld")]
+    #[test]
+    fn test_jsonld_none() {
+        let html = "<html><head><script type=\"application/ld+json\">[]</script></head></html>";
+        assert_eq!(extract_jsonld_article(html), None);
+    }
+}
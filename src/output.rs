@@ -0,0 +1,219 @@
+// Serialization helpers for classified paragraphs.
+
+use std::ops::Range;
+
+use crate::paragraph::Paragraph;
+
+/// Serialize paragraphs as newline-delimited JSON, one object per line.
+///
+/// Each object has `text`, `xpath`, and `word_count`. When `good_only` is false every
+/// paragraph is emitted and a `class` field (e.g. `"good"`, `"neargood"`) is added;
+/// when true only non-boilerplate paragraphs are emitted and `class` is omitted.
+pub fn to_ndjson(paragraphs: &[Paragraph], good_only: bool) -> String {
+    let mut out = String::new();
+    for p in paragraphs {
+        if good_only && p.is_boilerplate() {
+            continue;
+        }
+        out.push_str("{\"text\":");
+        push_json_str(&p.text, &mut out);
+        out.push_str(",\"xpath\":");
+        push_json_str(&p.xpath, &mut out);
+        out.push_str(",\"word_count\":");
+        out.push_str(&p.words_count.to_string());
+        if !good_only {
+            out.push_str(",\"class\":");
+            push_json_str(p.class_type.as_str(), &mut out);
+        }
+        out.push_str("}\n");
+    }
+    out
+}
+
+/// Serialize paragraphs as CSV with a header row, one paragraph per record.
+///
+/// Columns: `dom_path`, `xpath`, `word_count`, `link_char_count`, `tag_count`,
+/// `class_type`, `heading`, `text`. Fields containing commas, quotes, or line breaks
+/// are quoted per RFC 4180 (embedded quotes doubled). Records end with `\n`.
+pub fn to_csv(paragraphs: &[Paragraph]) -> String {
+    let mut out = String::from(
+        "dom_path,xpath,word_count,link_char_count,tag_count,class_type,heading,text\n",
+    );
+    for p in paragraphs {
+        push_csv_field(&p.dom_path, &mut out);
+        out.push(',');
+        push_csv_field(&p.xpath, &mut out);
+        out.push(',');
+        out.push_str(&p.words_count.to_string());
+        out.push(',');
+        out.push_str(&p.chars_count_in_links.to_string());
+        out.push(',');
+        out.push_str(&p.tags_count.to_string());
+        out.push(',');
+        out.push_str(p.class_type.as_str());
+        out.push(',');
+        out.push_str(if p.heading { "true" } else { "false" });
+        out.push(',');
+        push_csv_field(&p.text, &mut out);
+        out.push('\n');
+    }
+    out
+}
+
+/// Write `s` as a CSV field, quoting it only when necessary.
+fn push_csv_field(s: &str, out: &mut String) {
+    if !s.contains([',', '"', '\n', '\r']) {
+        out.push_str(s);
+        return;
+    }
+    out.push('"');
+    out.push_str(&s.replace('"', "\"\""));
+    out.push('"');
+}
+
+/// Stylesheet embedded by [`to_debug_html`]: one color per class, boilerplate muted.
+const DEBUG_STYLE: &str = "\
+body { font-family: sans-serif; max-width: 50em; margin: 2em auto; }
+p { margin: 0.4em 0; padding: 0.3em 0.5em; border-left: 4px solid; }
+p::before { content: attr(class); font: bold 0.75em monospace; margin-right: 0.5em; }
+.good { border-color: #2e7d32; background: #e8f5e9; }
+.neargood { border-color: #9e9d24; background: #f9fbe7; }
+.short { border-color: #9e9e9e; background: #f5f5f5; color: #616161; }
+.bad { border-color: #c62828; background: #ffebee; color: #9e9e9e; }
+";
+
+/// Render every paragraph as a standalone HTML page with its class highlighted, like
+/// Python jusText's HTML output, for eyeballing a classification.
+///
+/// Each paragraph becomes `<p class="good">` (or `bad`, `short`, `neargood`, per
+/// `class_type`) with its xpath in `data-xpath`, in document order; an embedded
+/// `<style>` colors the classes. Text and attributes are HTML-escaped.
+pub fn to_debug_html(paragraphs: &[Paragraph]) -> String {
+    let mut out = String::from(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>jusText</title>\n<style>\n",
+    );
+    out.push_str(DEBUG_STYLE);
+    out.push_str("</style>\n</head>\n<body>\n");
+    for p in paragraphs {
+        out.push_str("<p class=\"");
+        out.push_str(p.class_type.as_str());
+        out.push_str("\" data-xpath=\"");
+        push_html_text(&p.xpath, &mut out);
+        out.push_str("\">");
+        push_html_text(&p.text, &mut out);
+        out.push_str("</p>\n");
+    }
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+/// Render paragraphs as simple HTML, one block element per line.
+///
+/// Headings become `<hN>` (using [`Paragraph::heading_level`]); everything else is
+/// wrapped in `<p>`. Text is HTML-escaped.
+pub(crate) fn paragraphs_to_html<'a>(
+    paragraphs: impl IntoIterator<Item = &'a Paragraph>,
+) -> String {
+    paragraphs_to_html_with_ranges(paragraphs).0
+}
+
+/// Like [`paragraphs_to_html`], also returning each paragraph's byte range in the
+/// output. A range spans the element including its tags, but not the newline after it.
+pub(crate) fn paragraphs_to_html_with_ranges<'a>(
+    paragraphs: impl IntoIterator<Item = &'a Paragraph>,
+) -> (String, Vec<Range<usize>>) {
+    let mut out = String::new();
+    let mut ranges = Vec::new();
+    for p in paragraphs {
+        let tag = match p.heading_level() {
+            Some(level @ 1..=6) => format!("h{level}"),
+            _ => "p".to_string(),
+        };
+        let start = out.len();
+        out.push('<');
+        out.push_str(&tag);
+        out.push('>');
+        push_html_text(&p.text, &mut out);
+        out.push_str("</");
+        out.push_str(&tag);
+        out.push('>');
+        ranges.push(start..out.len());
+        out.push('\n');
+    }
+    (out, ranges)
+}
+
+/// Write `s` HTML-escaped into `out`.
+fn push_html_text(s: &str, out: &mut String) {
+    for ch in s.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            c => out.push(c),
+        }
+    }
+}
+
+/// Write `s` as a quoted JSON string into `out`.
+///
+/// Escapes quotes, backslashes, and all control characters below U+0020.
+fn push_json_str(s: &str, out: &mut String) {
+    out.push('"');
+    for ch in s.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                out.push_str(&format!("\\u{:04x}", c as u32));
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_field_quoting() {
+        let mut out = String::new();
+        push_csv_field("plain", &mut out);
+        out.push('|');
+        push_csv_field("a,b", &mut out);
+        out.push('|');
+        push_csv_field("say \"hi\"\nbye", &mut out);
+        assert_eq!(out, "plain|\"a,b\"|\"say \"\"hi\"\"\nbye\"");
+    }
+
+    #[test]
+    fn test_paragraphs_to_html() {
+        let ps = [
+            Paragraph::builder()
+                .dom_path("html.body.h2")
+                .text("Title")
+                .build(),
+            Paragraph::builder()
+                .dom_path("html.body.div.p")
+                .text("Fish & <chips>")
+                .build(),
+        ];
+        assert_eq!(
+            paragraphs_to_html(&ps),
+            "<h2>Title</h2>\n<p>Fish &amp; &lt;chips&gt;</p>\n"
+        );
+    }
+
+    #[test]
+    fn test_json_escapes_control_chars() {
+        let mut out = String::new();
+        push_json_str("a\"b\\c\nd\u{0001}", &mut out);
+        assert_eq!(out, r#""a\"b\\c\nd\u0001""#);
+    }
+}
@@ -1,7 +1,11 @@
 use std::collections::HashSet;
 
 /// Classification label for a paragraph.
+///
+/// With the `serde` feature, serializes as the lowercase [`as_str`](Self::as_str) names.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum ClassType {
     Good,
     Bad,
@@ -9,17 +13,85 @@ pub enum ClassType {
     NearGood,
 }
 
+impl ClassType {
+    /// Lowercase name matching Python jusText's class labels (`"good"`, `"neargood"`, ...).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ClassType::Good => "good",
+            ClassType::Bad => "bad",
+            ClassType::Short => "short",
+            ClassType::NearGood => "neargood",
+        }
+    }
+}
+
+/// Which branch of the context-free classifier decided a paragraph's `initial_class`.
+///
+/// Variants follow the decision tree in order; the first matching branch wins.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[non_exhaustive]
+pub enum ClassReason {
+    /// Bad: link density above `max_link_density`.
+    LinkDensityTooHigh,
+    /// Bad: contains a copyright marker (`©`, `&copy`, or one of `copyright_markers`) and
+    /// `drop_copyright_paragraphs` is on.
+    CopyrightMarker,
+    /// Bad: inside a `<select>`.
+    InsideSelect,
+    /// Bad: inside a boilerplate ARIA landmark (`drop_landmark_boilerplate`).
+    BoilerplateLandmark,
+    /// Bad: inside an element whose class or id matches `blocklist_patterns`.
+    Blocklisted,
+    /// Bad: under an element with an event-handler attribute (`drop_interactive`).
+    Interactive,
+    /// Bad: a byline or timestamp line (`detect_metadata_lines` and `drop_metadata_lines`).
+    MetadataLine,
+    /// Bad: uppercase ratio above `max_uppercase_ratio`.
+    TooMuchUppercase,
+    /// Bad: alphabetic ratio below `min_alpha_ratio`.
+    TooFewLetters,
+    /// Bad: shorter than `length_low` and contains link text.
+    ShortWithLinks,
+    /// Short: shorter than `length_low`.
+    TooShort,
+    /// Good: longer than `length_high` with stopword density of at least `stopwords_high`.
+    GoodByLength,
+    /// NearGood: stopword density of at least `stopwords_high` but not longer than
+    /// `length_high`. Density alone qualifies it; it ends up Good only if revision
+    /// promotes it.
+    NearGoodByDensity,
+    /// NearGood: stopword density between `stopwords_low` and `stopwords_high`.
+    MediumStopwordDensity,
+    /// Bad: stopword density below `stopwords_low`.
+    LowStopwordDensity,
+}
+
+/// Alias for [`ClassReason`], the type stored in [`Paragraph::reason`].
+///
+/// The variants cover the branches as follows: `LinkDensityTooHigh`, `CopyrightMarker`,
+/// `TooShort`, and `LowStopwordDensity` as named; `InSelectElement` is
+/// [`ClassReason::InsideSelect`]; `HighStopwordDensity` is
+/// [`ClassReason::GoodByLength`] (long enough to be Good) or
+/// [`ClassReason::NearGoodByDensity`] (too short, so NearGood).
+pub type ClassificationReason = ClassReason;
+
 /// A classified text paragraph extracted from HTML.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct Paragraph {
     /// Dot-separated DOM path without ordinals (e.g., "body.div.p").
     pub dom_path: String,
     /// XPath with ordinals (e.g., "/html[1]/body[1]/div[2]/p[1]").
+    ///
+    /// Unique within a document: when one element yields several paragraphs, later
+    /// ones carry a `#n` suffix (e.g. "/html[1]/body[1]#2").
     pub xpath: String,
     /// Normalized text content.
     pub text: String,
-    /// Word count (whitespace-split).
+    /// Word count: whitespace-split, or per `Config::word_segmentation` once classified.
     pub words_count: usize,
     /// Character count inside `<a>` tags.
     pub chars_count_in_links: usize,
@@ -31,11 +103,52 @@ pub struct Paragraph {
     pub initial_class: ClassType,
     /// Whether this paragraph is a heading.
     pub heading: bool,
+    /// Whether this is the heading picked as the page title (`Config::detect_title`).
+    ///
+    /// At most one paragraph per document is flagged.
+    pub is_probably_title: bool,
+    /// Innermost ARIA landmark role (e.g. `"navigation"`, `"main"`) on an ancestor element.
+    pub landmark: Option<String>,
+    /// Whether an ancestor element's class or id matched `Config::blocklist_patterns`.
+    pub blocklisted: bool,
+    /// Whether an ancestor element carries an inline event-handler attribute
+    /// (`onclick`, `onmouseover`, ...), as ads and widget buttons often do.
+    pub interactive: bool,
+    /// Whether the paragraph looks like a byline or timestamp (`Config::detect_metadata_lines`).
+    ///
+    /// Set during classification; `false` until then.
+    pub is_metadata: bool,
+    /// Normalized text nodes joined to form `text`, in order; only with
+    /// `Config::keep_text_nodes`.
+    pub text_nodes: Option<Vec<String>>,
+    /// Byte offset in the input HTML where the paragraph's first visible character starts.
+    ///
+    /// Together with `source_end`, `&html[source_start..source_end]` spans the
+    /// paragraph's source from its first to its last visible character, markup
+    /// included. Both are `0` if the text couldn't be matched against the source
+    /// (e.g. with `preserve_scripts` markers or a visible `inline_removal_placeholder`),
+    /// and for paragraphs built by hand.
+    pub source_start: usize,
+    /// Byte offset in the input HTML just past the paragraph's last visible character.
+    pub source_end: usize,
+    /// Stopword density the classifier compared against the thresholds.
+    ///
+    /// Set during classification; `0.0` until then.
+    pub stopword_density: f64,
+    /// Link density the classifier compared against `max_link_density`.
+    ///
+    /// Set during classification; `0.0` until then.
+    pub link_density: f64,
+    /// Classifier branch that set `initial_class`; `None` until classification.
+    pub reason: Option<ClassReason>,
 }
 
 impl Paragraph {
     /// Create a new paragraph with the given path and text.
-    pub(crate) fn new(
+    ///
+    /// `words_count` is derived from `text`. Classification fields start out as
+    /// [`ClassType::Short`] until [`classify_and_revise`](crate::classify_and_revise) runs.
+    pub fn new(
         dom_path: String,
         xpath: String,
         text: String,
@@ -53,7 +166,56 @@ impl Paragraph {
             class_type: ClassType::Short,
             initial_class: ClassType::Short,
             heading: false,
+            is_probably_title: false,
+            landmark: None,
+            blocklisted: false,
+            interactive: false,
+            is_metadata: false,
+            text_nodes: None,
+            source_start: 0,
+            source_end: 0,
+            stopword_density: 0.0,
+            link_density: 0.0,
+            reason: None,
+        }
+    }
+
+    /// Start building a paragraph; see [`ParagraphBuilder`].
+    pub fn builder() -> ParagraphBuilder {
+        ParagraphBuilder::default()
+    }
+
+    /// Append `other` to this paragraph, keeping the derived metrics consistent.
+    ///
+    /// Text is joined with a single space (skipped if either side is empty),
+    /// `chars_count_in_links` and `tags_count` are summed, and `words_count` is
+    /// recomputed, so [`links_density`](Self::links_density) reflects the merged text.
+    /// `text_nodes` are chained, and the source range is extended to `other`'s end when
+    /// both are known (an empty `self` takes `other`'s range). Paths, landmark, and classification fields are kept from `self`; reclassify the
+    /// merged paragraph if its class matters. The new `words_count` splits on
+    /// whitespace; classification recounts it under any other
+    /// [`Config::word_segmentation`](crate::Config::word_segmentation).
+    pub fn merge(&mut self, other: &Paragraph) {
+        if !self.text.is_empty() && !other.text.is_empty() {
+            self.text.push(' ');
+        }
+        if other.source_end != 0 {
+            if self.text.is_empty() {
+                self.source_start = other.source_start;
+                self.source_end = other.source_end;
+            } else if self.source_end != 0 {
+                self.source_end = other.source_end;
+            }
         }
+        self.text.push_str(&other.text);
+        if let Some(nodes) = &other.text_nodes {
+            self.text_nodes
+                .get_or_insert_with(Vec::new)
+                .extend(nodes.iter().cloned());
+        }
+        self.words_count = self.text.split_whitespace().count();
+        self.chars_count_in_links += other.chars_count_in_links;
+        self.tags_count += other.tags_count;
     }
 
     /// Returns `true` if this paragraph is classified as boilerplate.
@@ -73,6 +235,17 @@ impl Paragraph {
         })
     }
 
+    /// Level of the innermost heading tag in `dom_path` (`1` for `h1`), if any.
+    pub fn heading_level(&self) -> Option<u8> {
+        self.dom_path
+            .split('.')
+            .rev()
+            .find_map(|seg| match seg.as_bytes() {
+                [b'h', d] if d.is_ascii_digit() => Some(d - b'0'),
+                _ => None,
+            })
+    }
+
     /// Link density: chars_count_in_links / text char count. Returns 0.0 if text is empty.
     ///
     /// Uses Unicode codepoint count (not byte length) to match Python's `len()` semantics.
@@ -85,6 +258,38 @@ impl Paragraph {
         }
     }
 
+    /// Uppercase letters / all letters. Non-letters are ignored; returns 0.0 if no letters.
+    pub fn uppercase_ratio(&self) -> f64 {
+        let (letters, upper) = self
+            .text
+            .chars()
+            .filter(|c| c.is_alphabetic())
+            .fold((0usize, 0usize), |(l, u), c| {
+                (l + 1, u + usize::from(c.is_uppercase()))
+            });
+        if letters == 0 {
+            0.0
+        } else {
+            upper as f64 / letters as f64
+        }
+    }
+
+    /// Alphabetic characters / non-whitespace characters. Returns 1.0 if there are none.
+    pub fn alpha_ratio(&self) -> f64 {
+        let (visible, alpha) = self
+            .text
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .fold((0usize, 0usize), |(v, a), c| {
+                (v + 1, a + usize::from(c.is_alphabetic()))
+            });
+        if visible == 0 {
+            1.0
+        } else {
+            alpha as f64 / visible as f64
+        }
+    }
+
     /// Count of words present in the stoplist (case-insensitive).
     pub fn stopwords_count(&self, stoplist: &HashSet<String>) -> usize {
         self.text
@@ -102,3 +307,59 @@ impl Paragraph {
         }
     }
 }
+
+/// Builder for [`Paragraph`], for tests, custom segmenters, and deserialization.
+///
+/// Unset fields default to empty strings and zero counts. `words_count` is derived
+/// from the text exactly as in [`Paragraph::new`]; classification fields start at
+/// their pre-classification defaults.
+///
+/// ```rust
+/// let p = justext::Paragraph::builder()
+///     .dom_path("html.body.p")
+///     .text("Hello brave new world")
+///     .build();
+/// assert_eq!(p.words_count, 4);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ParagraphBuilder {
+    dom_path: String,
+    xpath: String,
+    text: String,
+    chars_count_in_links: usize,
+    tags_count: usize,
+}
+
+impl ParagraphBuilder {
+    pub fn dom_path(mut self, dom_path: impl Into<String>) -> Self {
+        self.dom_path = dom_path.into();
+        self
+    }
+    pub fn xpath(mut self, xpath: impl Into<String>) -> Self {
+        self.xpath = xpath.into();
+        self
+    }
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.text = text.into();
+        self
+    }
+    pub fn chars_count_in_links(mut self, n: usize) -> Self {
+        self.chars_count_in_links = n;
+        self
+    }
+    pub fn tags_count(mut self, n: usize) -> Self {
+        self.tags_count = n;
+        self
+    }
+
+    /// Build the paragraph, deriving `words_count` from the text.
+    pub fn build(self) -> Paragraph {
+        Paragraph::new(
+            self.dom_path,
+            self.xpath,
+            self.text,
+            self.chars_count_in_links,
+            self.tags_count,
+        )
+    }
+}
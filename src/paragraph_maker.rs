@@ -1,12 +1,13 @@
 // Port of ParagraphMaker + PathInfo from Python jusText justext/core.py
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use ego_tree::NodeRef;
-use scraper::node::Node;
-use scraper::Html;
+use scraper::node::{Element, Node};
+use scraper::{ElementRef, Html};
 
 use crate::paragraph::Paragraph;
+use crate::Config;
 
 /// Tags that create paragraph boundaries when entered or exited.
 const PARAGRAPH_TAGS: &[&str] = &[
@@ -45,10 +46,32 @@ const PARAGRAPH_TAGS: &[&str] = &[
 ];
 
 /// Returns true if `tag` is a paragraph-boundary tag.
-fn is_paragraph_tag(tag: &str) -> bool {
+pub(crate) fn is_paragraph_tag(tag: &str) -> bool {
     PARAGRAPH_TAGS.contains(&tag)
 }
 
+/// `Config::paragraph_tags`, lowercased; `None` means the built-in [`PARAGRAPH_TAGS`].
+pub(crate) fn configured_paragraph_tags(config: &Config) -> Option<HashSet<String>> {
+    config
+        .paragraph_tags
+        .as_ref()
+        .map(|tags| tags.iter().map(|t| t.to_lowercase()).collect())
+}
+
+/// Returns true if `tag` is a paragraph boundary under `tags`, as built by
+/// [`configured_paragraph_tags`].
+pub(crate) fn is_boundary_tag(tag: &str, tags: Option<&HashSet<String>>) -> bool {
+    match tags {
+        Some(tags) => tags.contains(tag),
+        None => is_paragraph_tag(tag),
+    }
+}
+
+/// The built-in paragraph-boundary tags, as a starting point for `Config::paragraph_tags`.
+pub fn default_paragraph_tags() -> HashSet<String> {
+    PARAGRAPH_TAGS.iter().map(|t| t.to_string()).collect()
+}
+
 /// Tracks the current DOM path during the tree walk.
 ///
 /// Maintains both a dot-separated `dom` path (no ordinals) and a
@@ -103,6 +126,21 @@ impl PathInfo {
     }
 }
 
+/// The element at `xpath` as built by [`PathInfo::xpath`]; a `#n` suffix is ignored.
+pub(crate) fn element_at_xpath<'a>(doc: &'a Html, xpath: &str) -> Option<ElementRef<'a>> {
+    let path = xpath.split('#').next().unwrap_or_default();
+    let mut node = doc.tree.root();
+    for step in path.split('/').filter(|s| !s.is_empty()) {
+        let (tag, ordinal) = step.strip_suffix(']')?.split_once('[')?;
+        let ordinal: usize = ordinal.parse().ok()?;
+        node = node
+            .children()
+            .filter(|c| matches!(c.value(), Node::Element(el) if el.name() == tag))
+            .nth(ordinal.checked_sub(1)?)?;
+    }
+    ElementRef::wrap(node)
+}
+
 /// Normalizes whitespace in a text node, matching Python's `normalize_whitespace()`:
 /// - Runs containing `\n` or `\r` collapse to `\n`
 /// - Other whitespace runs (including no-break spaces) collapse to ` `
@@ -139,6 +177,136 @@ fn is_blank(s: &str) -> bool {
     s.chars().all(|c| c.is_whitespace())
 }
 
+/// Split text at blank lines: whitespace runs containing two or more `\n`.
+fn split_blank_lines(text: &str) -> Vec<&str> {
+    let mut segments = Vec::new();
+    let mut start = 0;
+    let mut run_start = None;
+    let mut newlines = 0;
+
+    for (i, ch) in text.char_indices() {
+        if ch.is_whitespace() {
+            if run_start.is_none() {
+                run_start = Some(i);
+                newlines = 0;
+            }
+            if ch == '\n' {
+                newlines += 1;
+            }
+        } else if let Some(rs) = run_start.take() {
+            if newlines >= 2 {
+                segments.push(&text[start..rs]);
+                start = i;
+            }
+        }
+    }
+    segments.push(&text[start..]);
+    segments
+}
+
+/// Returns true if the document contains any paragraph-boundary tag besides `<body>`.
+fn has_block_structure(doc: &Html, tags: Option<&HashSet<String>>) -> bool {
+    doc.tree.nodes().any(|n| match n.value() {
+        Node::Element(el) => el.name() != "body" && is_boundary_tag(el.name(), tags),
+        _ => false,
+    })
+}
+
+/// ARIA landmark roles recognized on ancestor elements.
+const LANDMARK_ROLES: &[&str] = &[
+    "banner",
+    "complementary",
+    "contentinfo",
+    "form",
+    "main",
+    "navigation",
+    "region",
+    "search",
+];
+
+/// Returns the first ARIA landmark role listed in the element's `role` attribute.
+fn landmark_role(el: &Element) -> Option<&'static str> {
+    let role = el.attr("role")?;
+    role.split_ascii_whitespace().find_map(|token| {
+        LANDMARK_ROLES
+            .iter()
+            .find(|r| r.eq_ignore_ascii_case(token))
+            .copied()
+    })
+}
+
+/// Returns true if the element's `id` or any `class` token matches a (lowercased) pattern.
+fn matches_blocklist(el: &Element, patterns: &[String]) -> bool {
+    if patterns.is_empty() {
+        return false;
+    }
+    let id = el.id().into_iter();
+    let classes = el.attr("class").into_iter().flat_map(str::split_whitespace);
+    id.chain(classes).any(|token| {
+        let token = token.to_lowercase();
+        patterns.iter().any(|pattern| {
+            if pattern.contains('*') {
+                glob_match(pattern, &token)
+            } else {
+                token.contains(pattern.as_str())
+            }
+        })
+    })
+}
+
+/// Returns true if the element carries an inline event-handler attribute (`onclick`,
+/// `onmouseover`, ...): any attribute named `on` followed by letters.
+fn has_event_handler(el: &Element) -> bool {
+    el.attrs().any(|(name, _)| {
+        name.len() > 2
+            && name[..2].eq_ignore_ascii_case("on")
+            && name[2..].bytes().all(|b| b.is_ascii_alphabetic())
+    })
+}
+
+/// Match `text` against a glob where `*` matches any run of characters.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = text.strip_prefix(first) else {
+        return false;
+    };
+    let mut parts: Vec<&str> = parts.collect();
+    let last = parts.pop().unwrap_or_default();
+    for part in parts {
+        match rest.find(part) {
+            Some(at) => rest = &rest[at + part.len()..],
+            None => return false,
+        }
+    }
+    rest.len() >= last.len() && rest.ends_with(last)
+}
+
+/// Context inherited from ancestor elements, one entry per open element.
+#[derive(Clone, Copy, Default)]
+struct Scope {
+    /// Innermost ARIA landmark role among the ancestors.
+    landmark: Option<&'static str>,
+    /// Whether an ancestor matched `Config::blocklist_patterns`.
+    blocklisted: bool,
+    /// Whether an ancestor carries an inline event-handler attribute.
+    interactive: bool,
+    /// Whether an ancestor is a `<pre>`.
+    in_pre: bool,
+}
+
+impl Scope {
+    /// Scope for a child element, inheriting from `self`.
+    fn child(&self, el: &Element) -> Self {
+        Self {
+            landmark: landmark_role(el).or(self.landmark),
+            blocklisted: self.blocklisted,
+            interactive: self.interactive || has_event_handler(el),
+            in_pre: self.in_pre || el.name() == "pre",
+        }
+    }
+}
+
 /// Accumulates text nodes into a paragraph during the DOM walk.
 struct ParagraphAccumulator {
     dom_path: String,
@@ -146,16 +314,21 @@ struct ParagraphAccumulator {
     text_nodes: Vec<String>,
     chars_count_in_links: usize,
     tags_count: usize,
+    scope: Scope,
+    /// Whether any text node was appended with its whitespace intact.
+    preserves_whitespace: bool,
 }
 
 impl ParagraphAccumulator {
-    fn new(path: &PathInfo) -> Self {
+    fn new(path: &PathInfo, scope: Scope) -> Self {
         Self {
             dom_path: path.dom(),
             xpath: path.xpath(),
             text_nodes: Vec::new(),
             chars_count_in_links: 0,
             tags_count: 0,
+            scope,
+            preserves_whitespace: false,
         }
     }
 
@@ -165,6 +338,12 @@ impl ParagraphAccumulator {
         normalized
     }
 
+    /// Append `text` verbatim, without whitespace normalization.
+    fn append_literal_text(&mut self, text: &str) {
+        self.text_nodes.push(text.to_string());
+        self.preserves_whitespace = true;
+    }
+
     fn contains_text(&self) -> bool {
         // Require at least one text node with non-whitespace content.
         // A lone " " from a <br> tag must not trigger a paragraph flush.
@@ -173,92 +352,276 @@ impl ParagraphAccumulator {
             .any(|t| t.chars().any(|c| !c.is_whitespace()))
     }
 
-    fn build(self) -> Paragraph {
+    fn build(self, keep_text_nodes: bool) -> Paragraph {
         let raw = self.text_nodes.join("");
-        // Final strip after joining, matching Python's `text_nodes.join("").strip()`
-        let text = normalize_whitespace(raw.trim());
-        Paragraph::new(
+        let text = if self.preserves_whitespace {
+            // Keep the first line's indentation; drop only surrounding blank lines.
+            raw.trim_start_matches(['\n', '\r']).trim_end().to_string()
+        } else {
+            // Final strip after joining, matching Python's `text_nodes.join("").strip()`
+            normalize_whitespace(raw.trim())
+        };
+        let mut paragraph = Paragraph::new(
             self.dom_path,
             self.xpath,
             text,
             self.chars_count_in_links,
             self.tags_count,
-        )
+        );
+        paragraph.landmark = self.scope.landmark.map(str::to_string);
+        paragraph.blocklisted = self.scope.blocklisted;
+        paragraph.interactive = self.scope.interactive;
+        if keep_text_nodes {
+            paragraph.text_nodes = Some(self.text_nodes);
+        }
+        paragraph
     }
 }
 
 /// Walk state threaded through the recursive DOM walk.
 struct Walker {
     path: PathInfo,
+    /// Inherited context for each open element, parallel to `path`.
+    scopes: Vec<Scope>,
     paragraphs: Vec<Paragraph>,
+    /// How many paragraphs have been emitted per element xpath, for disambiguation.
+    xpath_counts: HashMap<String, usize>,
     current: ParagraphAccumulator,
     link: bool,
-    br: bool,
+    /// Consecutive `<br>` tags seen since the last text or inline tag.
+    br_run: usize,
+    /// Treat blank lines inside text nodes as paragraph boundaries.
+    split_blank_lines: bool,
+    /// Inline tags that don't count toward `tags_count`.
+    ignored_inline_tags: Vec<String>,
+    /// Text a single `<br>` contributes: `"\n"` or `" "`.
+    br_text: &'static str,
+    /// Wrap `<sup>`/`<sub>` content in `^(...)`/`_(...)`.
+    preserve_scripts: bool,
+    /// Keep each `<details>` widget in one paragraph.
+    expand_details: bool,
+    /// Lowercased `Config::blocklist_patterns`.
+    blocklist_patterns: Vec<String>,
+    /// Lowercased `Config::paragraph_tags`; `None` uses [`PARAGRAPH_TAGS`].
+    paragraph_tags: Option<HashSet<String>>,
+    /// Paragraph cap; once reached, the walk stops.
+    max_paragraphs: Option<usize>,
+    /// Copy the accumulated text nodes onto each paragraph.
+    keep_text_nodes: bool,
+    /// Number of open `<details>` elements being kept whole.
+    details_depth: usize,
+    /// Append text under `<pre>` without normalizing its whitespace.
+    preserve_pre_whitespace: bool,
 }
 
 impl Walker {
-    fn new() -> Self {
+    fn new(doc: &Html, config: &Config) -> Self {
         let path = PathInfo::new();
-        let current = ParagraphAccumulator::new(&path);
+        let current = ParagraphAccumulator::new(&path, Scope::default());
+        let paragraph_tags = configured_paragraph_tags(config);
         Self {
             path,
+            scopes: Vec::new(),
             paragraphs: Vec::new(),
+            xpath_counts: HashMap::new(),
             current,
             link: false,
-            br: false,
+            br_run: 0,
+            split_blank_lines: config.split_plain_text_on_blank_lines
+                && !has_block_structure(doc, paragraph_tags.as_ref()),
+            ignored_inline_tags: config.ignored_inline_tags.clone(),
+            br_text: if config.br_as_newline { "\n" } else { " " },
+            preserve_scripts: config.preserve_scripts,
+            expand_details: config.expand_details,
+            blocklist_patterns: config
+                .blocklist_patterns
+                .iter()
+                .map(|p| p.trim().to_lowercase())
+                .filter(|p| !p.is_empty())
+                .collect(),
+            details_depth: 0,
+            paragraph_tags,
+            max_paragraphs: config.max_paragraphs,
+            keep_text_nodes: config.keep_text_nodes,
+            preserve_pre_whitespace: config.preserve_pre_whitespace,
+        }
+    }
+
+    /// Context inherited by the innermost open element.
+    fn scope(&self) -> Scope {
+        self.scopes.last().copied().unwrap_or_default()
+    }
+
+    /// Open an element: push it onto the path and derive its scope.
+    fn enter(&mut self, el: &Element) {
+        let mut scope = self.scope().child(el);
+        scope.blocklisted |= matches_blocklist(el, &self.blocklist_patterns);
+        self.path.push(el.name());
+        self.scopes.push(scope);
+    }
+
+    /// Close the innermost open element.
+    fn leave(&mut self) {
+        self.path.pop();
+        self.scopes.pop();
+    }
+
+    /// Count `node`'s preceding element siblings toward the current path's ordinals,
+    /// as if they had been walked.
+    fn replay_preceding_siblings(&mut self, node: NodeRef<Node>) {
+        let mut siblings: Vec<NodeRef<Node>> = node.prev_siblings().collect();
+        siblings.reverse();
+        for sibling in siblings {
+            if let Node::Element(el) = sibling.value() {
+                self.path.push(el.name());
+                self.path.pop();
+            }
         }
     }
 
+    /// Returns true if `tag` starts and ends a paragraph under the configured tag set.
+    fn is_boundary(&self, tag: &str) -> bool {
+        is_boundary_tag(tag, self.paragraph_tags.as_ref())
+    }
+
+    /// Returns true once `max_paragraphs` paragraphs have been emitted.
+    fn is_full(&self) -> bool {
+        self.max_paragraphs
+            .is_some_and(|max| self.paragraphs.len() >= max)
+    }
+
     /// Flush the current paragraph accumulator and start a new one.
     fn start_new_paragraph(&mut self) {
-        let finished = std::mem::replace(&mut self.current, ParagraphAccumulator::new(&self.path));
-        if finished.contains_text() {
-            self.paragraphs.push(finished.build());
+        let next = ParagraphAccumulator::new(&self.path, self.scope());
+        let finished = std::mem::replace(&mut self.current, next);
+        if finished.contains_text() && !self.is_full() {
+            let mut paragraph = finished.build(self.keep_text_nodes);
+            let count = self
+                .xpath_counts
+                .entry(paragraph.xpath.clone())
+                .or_insert(0);
+            *count += 1;
+            if *count > 1 {
+                paragraph.xpath = format!("{}#{}", paragraph.xpath, count);
+            }
+            self.paragraphs.push(paragraph);
         }
-        self.br = false;
+        self.br_run = 0;
+    }
+
+    /// Append a text node's content to the current paragraph.
+    fn append_text_node(&mut self, content: &str) {
+        if self.preserve_pre_whitespace && self.scope().in_pre {
+            self.current.append_literal_text(content);
+            if self.link {
+                self.current.chars_count_in_links += content.chars().count();
+            }
+            self.br_run = 0;
+            return;
+        }
+        if is_blank(content) {
+            return;
+        }
+        let normalized = self.current.append_text(content);
+        if self.link {
+            // Count Unicode codepoints, not bytes — matches Python's len() on str.
+            self.current.chars_count_in_links += normalized.chars().count();
+        }
+        self.br_run = 0;
     }
 
     fn visit_node(&mut self, node: NodeRef<Node>) {
+        if self.is_full() {
+            return;
+        }
         match node.value() {
             Node::Element(el) => {
                 let tag = el.name();
 
-                self.path.push(tag);
+                self.enter(el);
 
-                if is_paragraph_tag(tag) {
+                if tag == "details" && self.expand_details {
+                    // Summary and body form one question/answer paragraph.
+                    if self.details_depth == 0 {
+                        self.start_new_paragraph();
+                    }
+                    self.details_depth += 1;
+                    for child in node.children() {
+                        self.visit_node(child);
+                    }
+                    self.details_depth -= 1;
+                    self.leave();
+                    if self.details_depth == 0 {
+                        self.start_new_paragraph();
+                    }
+                } else if self.details_depth > 0 && (self.is_boundary(tag) || tag == "summary") {
+                    // Block boundaries inside the widget only separate words.
+                    let _ = self.current.append_text(" ");
+                    for child in node.children() {
+                        self.visit_node(child);
+                    }
+                    let _ = self.current.append_text(" ");
+                    self.leave();
+                } else if self.is_boundary(tag) {
                     self.start_new_paragraph();
                     // Recurse into children
                     for child in node.children() {
                         self.visit_node(child);
                     }
-                    self.path.pop();
+                    self.leave();
                     self.start_new_paragraph();
                 } else if tag == "br" {
-                    if self.br {
-                        // Second consecutive <br>: paragraph boundary.
-                        // Undo the tag_count increment from the first <br>.
-                        self.current.tags_count = self.current.tags_count.saturating_sub(1);
-                        self.path.pop();
-                        self.start_new_paragraph();
-                    } else {
-                        // First <br>: insert a space, set br flag.
-                        self.br = true;
-                        let _ = self.current.append_text(" ");
-                        self.current.tags_count += 1;
-                        self.path.pop();
+                    self.br_run += 1;
+                    match self.br_run {
+                        1 => {
+                            // First <br>: insert a space (or newline).
+                            let _ = self.current.append_text(self.br_text);
+                            self.current.tags_count += 1;
+                            self.leave();
+                        }
+                        2 => {
+                            // Second consecutive <br>: paragraph boundary.
+                            // Undo the tag_count increment from the first <br>.
+                            self.current.tags_count = self.current.tags_count.saturating_sub(1);
+                            self.leave();
+                            self.start_new_paragraph();
+                            // Keep counting so further <br>s in the run are absorbed.
+                            self.br_run = 2;
+                        }
+                        _ => {
+                            // Third and later: the boundary is already taken.
+                            self.leave();
+                        }
                     }
                 } else {
                     // Inline tag
                     if tag == "a" {
                         self.link = true;
                     }
-                    self.current.tags_count += 1;
-                    self.br = false;
-
+                    if !self
+                        .ignored_inline_tags
+                        .iter()
+                        .any(|t| t.eq_ignore_ascii_case(tag))
+                    {
+                        self.current.tags_count += 1;
+                    }
+                    self.br_run = 0;
+
+                    let script_marker = match tag {
+                        "sup" if self.preserve_scripts => Some("^("),
+                        "sub" if self.preserve_scripts => Some("_("),
+                        _ => None,
+                    };
+                    if let Some(marker) = script_marker {
+                        let _ = self.current.append_text(marker);
+                    }
                     for child in node.children() {
                         self.visit_node(child);
                     }
-                    self.path.pop();
+                    if script_marker.is_some() {
+                        let _ = self.current.append_text(")");
+                    }
+                    self.leave();
 
                     if tag == "a" {
                         self.link = false;
@@ -267,15 +630,18 @@ impl Walker {
             }
             Node::Text(text) => {
                 let content = text.text.as_ref();
-                if is_blank(content) {
+                if !self.split_blank_lines {
+                    self.append_text_node(content);
                     return;
                 }
-                let normalized = self.current.append_text(content);
-                if self.link {
-                    // Count Unicode codepoints, not bytes — matches Python's len() on str.
-                    self.current.chars_count_in_links += normalized.chars().count();
+                let segments = split_blank_lines(content);
+                let last = segments.len() - 1;
+                for (i, segment) in segments.into_iter().enumerate() {
+                    self.append_text_node(segment);
+                    if i < last {
+                        self.start_new_paragraph();
+                    }
                 }
-                self.br = false;
             }
             // Document / fragment: recurse into children
             Node::Document | Node::Fragment => {
@@ -292,14 +658,42 @@ impl Walker {
 /// Convert a preprocessed HTML document into a list of paragraphs.
 ///
 /// Port of `ParagraphMaker.make_paragraphs()` from Python jusText.
-pub fn make_paragraphs(doc: &Html) -> Vec<Paragraph> {
-    let mut walker = Walker::new();
+///
+/// Unlike Python, xpaths are unique per document: when one element yields several
+/// paragraphs (text around a nested block, or a `<br><br>` split), the first keeps the
+/// element's xpath and later ones get a `#n` suffix, e.g. `/html[1]/body[1]#2`.
+pub fn make_paragraphs(doc: &Html, config: &Config) -> Vec<Paragraph> {
+    let mut walker = Walker::new(doc, config);
     walker.visit_node(doc.tree.root());
     // Flush any remaining paragraph (mirrors Python's endDocument handler)
     walker.start_new_paragraph();
     walker.paragraphs
 }
 
+/// Like [`make_paragraphs`], but only walks the subtree rooted at `node`.
+///
+/// The path is seeded from `node`'s ancestors, including sibling ordinals, so
+/// `dom_path` and `xpath` are the same as a whole-document walk would produce, and
+/// ancestor context such as ARIA landmarks still applies.
+pub fn make_paragraphs_in(doc: &Html, node: NodeRef<Node>, config: &Config) -> Vec<Paragraph> {
+    let mut walker = Walker::new(doc, config);
+
+    let mut ancestors: Vec<NodeRef<Node>> = node.ancestors().collect();
+    ancestors.reverse();
+    for ancestor in ancestors {
+        if let Node::Element(el) = ancestor.value() {
+            walker.replay_preceding_siblings(ancestor);
+            walker.enter(el);
+        }
+    }
+    walker.replay_preceding_siblings(node);
+    walker.current = ParagraphAccumulator::new(&walker.path, walker.scope());
+
+    walker.visit_node(node);
+    walker.start_new_paragraph();
+    walker.paragraphs
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -307,7 +701,7 @@ mod tests {
 
     fn parse(html: &str) -> Vec<Paragraph> {
         let doc = preprocess(html);
-        make_paragraphs(&doc)
+        make_paragraphs(&doc, &Config::default())
     }
 
     // --- Port of test_sax.py ---
@@ -475,6 +869,30 @@ mod tests {
         assert_eq!(ps[0].text, "abc def becoming abcdef");
     }
 
+    #[test]
+    fn test_consecutive_br_runs_are_one_boundary() {
+        for run in 2..=4 {
+            let brs = "<br>".repeat(run);
+            let html = format!("<html><body>first text{brs}second text</body></html>");
+            let ps = parse(&html);
+            assert_eq!(ps.len(), 2, "{run} <br>s");
+            assert_eq!(ps[0].text, "first text");
+            assert_eq!(ps[0].tags_count, 0, "{run} <br>s");
+            assert_eq!(ps[1].text, "second text");
+            assert_eq!(ps[1].tags_count, 0, "{run} <br>s");
+            assert_eq!(ps[1].xpath, "/html[1]/body[1]#2");
+        }
+    }
+
+    #[test]
+    fn test_consecutive_br_runs_with_whitespace_between() {
+        let html = "<html><body>first<br>\n<br>\n<br> second<br>\n\n<br>third</body></html>";
+        let ps = parse(html);
+        let texts: Vec<&str> = ps.iter().map(|p| p.text.as_str()).collect();
+        assert_eq!(texts, ["first", "second", "third"]);
+        assert!(ps.iter().all(|p| p.tags_count == 0));
+    }
+
     #[test]
     fn test_br_br_only_emits_no_paragraphs() {
         // Double <br> with no surrounding text must not flush an empty paragraph.
@@ -483,6 +901,97 @@ mod tests {
         assert_eq!(ps.len(), 0, "<br><br> alone must not produce any paragraph");
     }
 
+    #[test]
+    fn test_split_plain_text_on_blank_lines() {
+        let html =
+            "<html><body>first block\nstill first\n\n  second block\n \n\nthird</body></html>";
+        let config = Config::default().with_split_plain_text_on_blank_lines(true);
+        let ps = make_paragraphs(&preprocess(html), &config);
+        let texts: Vec<&str> = ps.iter().map(|p| p.text.as_str()).collect();
+        assert_eq!(
+            texts,
+            vec!["first block\nstill first", "second block", "third"]
+        );
+        assert!(ps.iter().all(|p| p.dom_path == "html.body"));
+    }
+
+    #[test]
+    fn test_split_plain_text_default_off() {
+        let html = "<html><body>first block\n\nsecond block</body></html>";
+        let ps = parse(html);
+        assert_eq!(ps.len(), 1);
+        assert_eq!(ps[0].text, "first block\nsecond block");
+    }
+
+    #[test]
+    fn test_split_plain_text_ignores_block_structured_pages() {
+        let html = "<html><body><p>first block\n\nsecond block</p></body></html>";
+        let config = Config::default().with_split_plain_text_on_blank_lines(true);
+        let ps = make_paragraphs(&preprocess(html), &config);
+        assert_eq!(ps.len(), 1);
+        assert_eq!(ps[0].text, "first block\nsecond block");
+    }
+
+    #[test]
+    fn test_split_blank_lines_helper() {
+        assert_eq!(split_blank_lines("a\nb"), vec!["a\nb"]);
+        assert_eq!(split_blank_lines("a\r\n\r\nb"), vec!["a", "b"]);
+        assert_eq!(split_blank_lines("a \n\t\n b \n\n"), vec!["a", "b \n\n"]);
+    }
+
+    #[test]
+    fn test_landmark_role_inherited() {
+        let html = concat!(
+            "<html><body>",
+            "<div role=\"navigation\"><ul><li>Home</li></ul></div>",
+            "<div role=\"main\"><p>Article</p></div>",
+            "<p>Loose</p>",
+            "</body></html>"
+        );
+        let ps = parse(html);
+        assert_eq!(ps.len(), 3);
+        assert_eq!(ps[0].landmark.as_deref(), Some("navigation"));
+        assert_eq!(ps[1].landmark.as_deref(), Some("main"));
+        assert_eq!(ps[2].landmark, None);
+    }
+
+    #[test]
+    fn test_landmark_innermost_role_wins() {
+        let html = "<html><body><div role=\"banner\"><nav role=\"Search\"><p>x</p></nav></div></body></html>";
+        let ps = parse(html);
+        assert_eq!(ps[0].landmark.as_deref(), Some("search"));
+    }
+
+    #[test]
+    fn test_xpaths_unique_when_element_yields_several_paragraphs() {
+        let html = "<html><body>intro<div>middle</div>outro<br><br>tail</body></html>";
+        let ps = parse(html);
+        let xpaths: Vec<&str> = ps.iter().map(|p| p.xpath.as_str()).collect();
+        assert_eq!(
+            xpaths,
+            vec![
+                "/html[1]/body[1]",
+                "/html[1]/body[1]/div[1]",
+                "/html[1]/body[1]#2",
+                "/html[1]/body[1]#3",
+            ]
+        );
+        let unique: std::collections::HashSet<&str> = xpaths.iter().copied().collect();
+        assert_eq!(unique.len(), xpaths.len());
+    }
+
+    #[test]
+    fn test_xpaths_unique_for_foster_parented_text() {
+        // Stray text inside <table> is foster-parented before the table, leaving body
+        // text on both sides of it.
+        let html = "<html><body>lead<table><tr><td>a</td></tr>stray</table>after</body></html>";
+        let ps = parse(html);
+        assert_eq!(ps[0].xpath, "/html[1]/body[1]");
+        assert_eq!(ps[2].xpath, "/html[1]/body[1]#2");
+        let unique: std::collections::HashSet<&str> = ps.iter().map(|p| p.xpath.as_str()).collect();
+        assert_eq!(unique.len(), ps.len());
+    }
+
     // --- Port of test_paths.py ---
 
     #[test]
@@ -620,4 +1129,267 @@ mod tests {
         let expected = " € ";
         assert_eq!(normalize_whitespace(input), expected);
     }
+
+    #[test]
+    fn test_make_paragraphs_in_matches_full_walk() {
+        let html = concat!(
+            "<html><body>",
+            "<div><p>Intro</p></div>",
+            "<div role=\"navigation\"><p>Nav</p></div>",
+            "<section><p>Skip</p></section>",
+            "<section><p>First</p><div>Second</div></section>",
+            "</body></html>"
+        );
+        let doc = preprocess(html);
+        let full = make_paragraphs(&doc, &Config::default());
+
+        let sel = scraper::Selector::parse("section").unwrap();
+        let second = doc.select(&sel).nth(1).unwrap();
+        let scoped = make_paragraphs_in(&doc, *second, &Config::default());
+        assert_eq!(scoped, full[3..]);
+        assert_eq!(scoped[0].xpath, "/html[1]/body[1]/section[2]/p[1]");
+
+        let nav = doc
+            .select(&scraper::Selector::parse("div").unwrap())
+            .nth(1)
+            .unwrap();
+        let scoped = make_paragraphs_in(&doc, *nav, &Config::default());
+        assert_eq!(scoped[0].landmark.as_deref(), Some("navigation"));
+        assert_eq!(scoped[0].xpath, "/html[1]/body[1]/div[2]/p[1]");
+    }
+
+    #[test]
+    fn test_ignored_inline_tags() {
+        let html = "<html><body><p>super<wbr>cali<wbr>fragilistic <em>word</em></p></body></html>";
+        let doc = preprocess(html);
+        assert_eq!(make_paragraphs(&doc, &Config::default())[0].tags_count, 3);
+
+        let config = Config::default().with_ignored_inline_tags(vec!["WBR".to_string()]);
+        let ps = make_paragraphs(&doc, &config);
+        assert_eq!(ps[0].tags_count, 1);
+        assert_eq!(ps[0].text, "supercalifragilistic word");
+    }
+
+    #[test]
+    fn test_br_as_newline() {
+        let html = "<html><body><p>line1<br>line2<br><br>next</p></body></html>";
+        let doc = preprocess(html);
+        let ps = make_paragraphs(&doc, &Config::default());
+        assert_eq!(ps[0].text, "line1 line2");
+
+        let ps = make_paragraphs(&doc, &Config::default().with_br_as_newline(true));
+        assert_eq!(ps.len(), 2);
+        assert_eq!(ps[0].text, "line1\nline2");
+        assert_eq!(ps[0].words_count, 2);
+        assert_eq!(ps[1].text, "next");
+    }
+
+    #[test]
+    fn test_blocklist_patterns() {
+        let doc = preprocess(concat!(
+            "<html><body>",
+            "<div class=\"Wrapper AD-Banner\"><p>Buy now</p></div>",
+            "<div id=\"sidebar-left\"><p>Links</p></div>",
+            "<div class=\"article\"><p>Story</p></div>",
+            "</body></html>"
+        ));
+        let blocked = |patterns: &[&str]| -> Vec<bool> {
+            let config = Config::default()
+                .with_blocklist_patterns(patterns.iter().map(|p| p.to_string()).collect());
+            make_paragraphs(&doc, &config)
+                .iter()
+                .map(|p| p.blocklisted)
+                .collect()
+        };
+        assert_eq!(blocked(&[]), [false, false, false]);
+        assert_eq!(blocked(&["ad-*", "SIDEBAR"]), [true, true, false]);
+        // Globs match whole tokens, so "*ad" doesn't hit "ad-banner".
+        assert_eq!(blocked(&["*ad"]), [false, false, false]);
+    }
+
+    #[test]
+    fn test_interactive_inherited() {
+        let doc = preprocess(concat!(
+            "<html><body>",
+            "<div onClick=\"track()\"><p>Click here</p></div>",
+            "<p onmouseover=\"x()\">Hover</p>",
+            "<p data-on=\"1\">Plain</p>",
+            "</body></html>"
+        ));
+        let flags: Vec<bool> = make_paragraphs(&doc, &Config::default())
+            .iter()
+            .map(|p| p.interactive)
+            .collect();
+        assert_eq!(flags, [true, true, false]);
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("ad-*", "ad-banner"));
+        assert!(glob_match("*-ad-*", "top-ad-slot"));
+        assert!(glob_match("a*a", "aba"));
+        assert!(!glob_match("a*a", "a"));
+        assert!(!glob_match("ad-*", "head-ad"));
+    }
+
+    #[test]
+    fn test_keep_text_nodes() {
+        let doc = preprocess("<html><body><p>Hello <b>big</b> <i>wide</i>world</p></body></html>");
+
+        let ps = make_paragraphs(&doc, &Config::default());
+        assert_eq!(ps[0].text_nodes, None);
+
+        let ps = make_paragraphs(&doc, &Config::default().with_keep_text_nodes(true));
+        // The whitespace-only node between </b> and <i> is dropped, which is why
+        // "big" and "wide" merge.
+        assert_eq!(ps[0].text, "Hello bigwideworld");
+        assert_eq!(
+            ps[0].text_nodes.as_deref(),
+            Some(&["Hello ", "big", "wide", "world"].map(String::from)[..])
+        );
+    }
+
+    #[test]
+    fn test_preserve_pre_whitespace() {
+        let doc = preprocess(concat!(
+            "<html><body><p>Run   this:</p>",
+            "<pre><code>fn main() {\n    println!(\"hi\");\n\n    <b>exit</b>(0);\n}\n</code></pre>",
+            "</body></html>"
+        ));
+        let texts = |config: &Config| -> Vec<String> {
+            make_paragraphs(&doc, config)
+                .into_iter()
+                .map(|p| p.text)
+                .collect()
+        };
+        assert_eq!(
+            texts(&Config::default()),
+            ["Run this:", "fn main() {\nprintln!(\"hi\");\nexit(0);\n}"]
+        );
+        assert_eq!(
+            texts(&Config::default().with_preserve_pre_whitespace(true)),
+            [
+                "Run this:",
+                "fn main() {\n    println!(\"hi\");\n\n    exit(0);\n}"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_paragraph_tags() {
+        let doc = preprocess(
+            "<html><body>Lead <article-body>First</article-body><p>Second</p><p>Third</p>\
+             </body></html>",
+        );
+        let texts = |config: &Config| -> Vec<String> {
+            make_paragraphs(&doc, config)
+                .into_iter()
+                .map(|p| p.text)
+                .collect()
+        };
+        assert_eq!(texts(&Config::default()), ["Lead First", "Second", "Third"]);
+
+        let mut tags = default_paragraph_tags();
+        tags.insert("Article-Body".to_string());
+        let config = Config::default().with_paragraph_tags(Some(tags));
+        assert_eq!(texts(&config), ["Lead", "First", "Second", "Third"]);
+
+        // A replacement set without "p" no longer splits there.
+        let only_custom = ["article-body".to_string()].into_iter().collect();
+        let config = Config::default().with_paragraph_tags(Some(only_custom));
+        assert_eq!(texts(&config), ["Lead", "First", "SecondThird"]);
+    }
+
+    #[test]
+    fn test_paragraph_tags_decide_blank_line_splitting() {
+        // Only a custom block tag: with the default set the document looks unstructured.
+        let doc = preprocess(
+            "<html><body>first block\n\nsecond block<article-body>Body</article-body>\
+             </body></html>",
+        );
+        let config = Config::default().with_split_plain_text_on_blank_lines(true);
+        let ps = make_paragraphs(&doc, &config);
+        let texts: Vec<&str> = ps.iter().map(|p| p.text.as_str()).collect();
+        assert_eq!(texts, ["first block", "second blockBody"]);
+
+        // Once it is a boundary tag the document has block structure, so blank lines
+        // no longer split.
+
+        let mut tags = default_paragraph_tags();
+        tags.insert("article-body".to_string());
+        let ps = make_paragraphs(&doc, &config.with_paragraph_tags(Some(tags)));
+        let texts: Vec<&str> = ps.iter().map(|p| p.text.as_str()).collect();
+        assert_eq!(texts, ["first block\nsecond block", "Body"]);
+    }
+
+    #[test]
+    fn test_max_paragraphs() {
+        let html = format!(
+            "<html><body>{}</body></html>",
+            (0..50)
+                .map(|i| format!("<p>Paragraph {i}</p>"))
+                .collect::<String>()
+        );
+        let doc = preprocess(&html);
+        assert_eq!(make_paragraphs(&doc, &Config::default()).len(), 50);
+
+        let ps = make_paragraphs(&doc, &Config::default().with_max_paragraphs(Some(3)));
+        let texts: Vec<&str> = ps.iter().map(|p| p.text.as_str()).collect();
+        assert_eq!(texts, ["Paragraph 0", "Paragraph 1", "Paragraph 2"]);
+
+        let ps = make_paragraphs(&doc, &Config::default().with_max_paragraphs(Some(0)));
+        assert!(ps.is_empty());
+    }
+
+    #[test]
+    fn test_expand_details() {
+        let html = concat!(
+            "<html><body><p>Frequently asked questions</p>",
+            "<details><summary>Can I cancel my order?</summary>",
+            "<p>Yes, within 14 days.</p><p>Refunds take a week.</p></details>",
+            "<details open><summary>Do you ship abroad?</summary>No.</details>",
+            "</body></html>"
+        );
+        let doc = preprocess(html);
+
+        let texts: Vec<String> = make_paragraphs(&doc, &Config::default())
+            .into_iter()
+            .map(|p| p.text)
+            .collect();
+        assert_eq!(
+            texts,
+            [
+                "Frequently asked questions",
+                "Can I cancel my order?",
+                "Yes, within 14 days.",
+                "Refunds take a week.",
+                "Do you ship abroad?No."
+            ]
+        );
+
+        let ps = make_paragraphs(&doc, &Config::default().with_expand_details(true));
+        let texts: Vec<&str> = ps.iter().map(|p| p.text.as_str()).collect();
+        assert_eq!(
+            texts,
+            [
+                "Frequently asked questions",
+                "Can I cancel my order? Yes, within 14 days. Refunds take a week.",
+                "Do you ship abroad? No."
+            ]
+        );
+        assert_eq!(ps[1].dom_path, "html.body.details");
+    }
+
+    #[test]
+    fn test_preserve_scripts() {
+        let html =
+            "<html><body><p>H<sub>2</sub>O and x<sup>2</sup> and <em>y</em></p></body></html>";
+        let doc = preprocess(html);
+        let ps = make_paragraphs(&doc, &Config::default());
+        assert_eq!(ps[0].text, "H2O and x2 and y");
+
+        let ps = make_paragraphs(&doc, &Config::default().with_preserve_scripts(true));
+        assert_eq!(ps[0].text, "H_(2)O and x^(2) and y");
+        assert_eq!(ps[0].tags_count, 3);
+    }
 }
@@ -1,6 +1,17 @@
 // Port of Python jusText preprocessor() from justext/core.py
 
-use scraper::Html;
+use std::collections::HashMap;
+
+use ego_tree::iter::Edge;
+use ego_tree::{NodeMut, NodeRef};
+use scraper::node::{Node, Text};
+use scraper::{Html, StrTendril};
+
+use crate::entities::{entity_map, EntityHandling};
+use crate::paragraph_maker::{
+    configured_paragraph_tags, is_boundary_tag, normalize_whitespace, PathInfo,
+};
+use crate::Config;
 
 /// Tags to completely remove (including all children).
 const REMOVE_TAGS: &[&str] = &[
@@ -24,45 +35,185 @@ const REMOVE_TAG_KEEP_CHILDREN: &[&str] = &["form"];
 /// Mirrors the Python `preprocessor()` which uses lxml's Cleaner with:
 /// - scripts=True, comments=True, style=True, embedded=True, forms=True
 /// - kill_tags=("head",)
+#[cfg(test)]
 pub fn preprocess(html: &str) -> Html {
-    // Scraper parses into an owned Html; we must rebuild without unwanted nodes.
-    // Strategy: serialize to string after stripping unwanted tags, then reparse.
-    let cleaned = remove_tags_and_comments(html);
-    Html::parse_document(&cleaned)
+    preprocess_with_placeholder(html, None)
+}
+
+/// Like [`preprocess`], but writes `placeholder` (if any) as text where a removed
+/// element stood, so the words around it don't merge.
+pub(crate) fn preprocess_with_placeholder(html: &str, placeholder: Option<char>) -> Html {
+    preprocess_document(&Html::parse_document(html), placeholder)
+}
+
+/// Like [`preprocess_with_placeholder`], for a document that is already parsed.
+pub(crate) fn preprocess_document(parsed: &Html, placeholder: Option<char>) -> Html {
+    // Detaching nodes from scraper's arena leaves them reachable through `tree.nodes()`
+    // (which `Html::select` iterates), so copy the kept nodes into a fresh tree instead.
+    let mut cleaned = Html::new_document();
+    cleaned.quirks_mode = parsed.quirks_mode;
+    let mut placeholder_buf = [0; 4];
+    let placeholder = placeholder.map(|ch| &*ch.encode_utf8(&mut placeholder_buf));
+    copy_kept_children(
+        parsed.tree.root(),
+        &mut cleaned.tree.root_mut(),
+        placeholder,
+    );
+    cleaned.errors = parsed.errors.clone();
+    cleaned
+}
+
+/// Visible text of the cleaned document, similar to a browser's `innerText`.
+///
+/// Applies the same preprocessing as the classifier (so script, style, and form-control
+/// text is excluded), concatenates all remaining text nodes, and normalizes whitespace.
+/// Paragraph-boundary tags contribute a line break so adjacent blocks don't merge.
+/// No classification is performed.
+pub fn visible_text(html: &str) -> String {
+    visible_text_with(html, &Config::default())
+}
+
+/// Like [`visible_text`], preprocessed as the classifier would with `config`: line
+/// breaks come from `config.paragraph_tags` (so lines match the paragraph boundaries),
+/// and `inline_removal_placeholder` is applied.
+pub fn visible_text_with(html: &str, config: &Config) -> String {
+    let doc = preprocess_with_placeholder(html, config.inline_removal_placeholder);
+    let tags = configured_paragraph_tags(config);
+    let mut raw = String::with_capacity(html.len() / 2);
+    for edge in doc.tree.root().traverse() {
+        match edge {
+            Edge::Open(node) | Edge::Close(node) => match node.value() {
+                Node::Element(el) if is_boundary_tag(el.name(), tags.as_ref()) => raw.push('\n'),
+                Node::Text(text) if matches!(edge, Edge::Open(_)) => raw.push_str(&text.text),
+                _ => {}
+            },
+        }
+    }
+    normalize_whitespace(raw.trim())
 }
 
-/// Remove unwanted tags and HTML comments via string manipulation before parsing.
+/// The cleaned HTML that the classifier sees, serialized back to a string.
 ///
-/// This is simpler and more reliable than trying to mutate scraper's arena.
-fn remove_tags_and_comments(html: &str) -> String {
-    // We do a two-pass approach:
-    // 1. Parse with scraper to get a proper DOM
-    // 2. Walk the tree, skipping unwanted nodes, and rebuild the text
+/// Scripts, styles, `<head>`, comments, form controls, and embedded content are
+/// removed. With [`EntityHandling::Decode`] (the default) named entities come out as
+/// the characters they decode to. With [`EntityHandling::Preserve`], characters that
+/// the source spelled as a named entity (see [`decoded_entities`](crate::decoded_entities))
+/// are written back as that entity in text content. The parser doesn't keep source
+/// positions, so this is per character: if the source has both `&mdash;` and a literal
+/// `—`, both are emitted as `&mdash;`. Attribute values are always decoded.
+pub fn clean_html(html: &str, entities: EntityHandling) -> String {
     let doc = Html::parse_document(html);
+    let entities = match entities {
+        EntityHandling::Decode => HashMap::new(),
+        EntityHandling::Preserve => entity_map(html),
+    };
     let mut out = String::with_capacity(html.len());
-    serialize_node(&doc.tree.root(), &mut out);
+    serialize_node(&doc.tree.root(), &mut out, &entities, None);
     out
 }
 
-/// Recursively serialize the node tree, skipping unwanted tags and comments.
-fn serialize_node(node: &ego_tree::NodeRef<scraper::node::Node>, out: &mut String) {
-    use scraper::node::Node;
+/// Serialize a preprocessed document, adding `attr="value"` to each element whose
+/// xpath (as computed by the paragraph walker) has an entry in `values`.
+pub(crate) fn serialize_annotated(
+    doc: &Html,
+    attr: &'static str,
+    values: &HashMap<String, String>,
+) -> String {
+    let mut annotations = Annotations {
+        path: PathInfo::new(),
+        attr,
+        values,
+    };
+    let mut out = String::new();
+    serialize_node(
+        &doc.tree.root(),
+        &mut out,
+        &HashMap::new(),
+        Some(&mut annotations),
+    );
+    out
+}
+
+/// Per-element attributes added by [`serialize_annotated`], keyed by xpath.
+struct Annotations<'a> {
+    path: PathInfo,
+    attr: &'static str,
+    values: &'a HashMap<String, String>,
+}
+
+/// Copy the children of `from` under `to`, skipping unwanted tags and comments.
+///
+/// Text nodes are never re-parsed, so decoded entities like `&lt;year&gt;` stay text.
+/// Adjacent text left behind by a removed node is merged into one node, as a parser
+/// would produce. A removed element (other than `<head>`) is replaced by `placeholder`
+/// as text, if given.
+fn copy_kept_children(from: NodeRef<Node>, to: &mut NodeMut<Node>, placeholder: Option<&str>) {
+    for child in from.children() {
+        match child.value() {
+            Node::Element(el) => {
+                let tag = el.name();
+                if REMOVE_TAGS.contains(&tag) {
+                    // Skip element and all its children.
+                    if let Some(text) = placeholder.filter(|_| tag != "head") {
+                        append_text(to, text);
+                    }
+                } else if REMOVE_TAG_KEEP_CHILDREN.contains(&tag) {
+                    // Drop the element but keep its children (content floats up).
+                    copy_kept_children(child, to, placeholder);
+                } else {
+                    let mut copy = to.append(Node::Element(el.clone()));
+                    copy_kept_children(child, &mut copy, placeholder);
+                }
+            }
+            Node::Text(text) => append_text(to, &text.text),
+            // Skip comments and doctypes.
+            // Note: Python's Cleaner has processing_instructions=False (preserves PIs), but PIs
+            // are vanishingly rare in real-world HTML so we strip them here for simplicity.
+            Node::Comment(_) | Node::ProcessingInstruction(_) | Node::Doctype(_) => {}
+            Node::Document | Node::Fragment => copy_kept_children(child, to, placeholder),
+        }
+    }
+}
+
+/// Append `text` under `parent`, extending its last child if that is already a text node.
+fn append_text(parent: &mut NodeMut<Node>, text: &str) {
+    if let Some(mut last) = parent.last_child() {
+        if let Node::Text(existing) = last.value() {
+            existing.text.push_slice(text);
+            return;
+        }
+    }
+    parent.append(Node::Text(Text {
+        text: StrTendril::from_slice(text),
+    }));
+}
 
+/// Recursively serialize the node tree, skipping unwanted tags and comments.
+///
+/// Characters in `entities` are written as `&name;` in text content. With
+/// `annotations`, elements whose xpath has a value get the extra attribute.
+fn serialize_node(
+    node: &ego_tree::NodeRef<Node>,
+    out: &mut String,
+    entities: &HashMap<char, &'static str>,
+    mut annotations: Option<&mut Annotations>,
+) {
     match node.value() {
         Node::Document => {
             for child in node.children() {
-                serialize_node(&child, out);
+                serialize_node(&child, out, entities, annotations.as_deref_mut());
             }
         }
         Node::Element(el) => {
             let tag = el.name();
             if REMOVE_TAGS.contains(&tag) {
-                return; // skip element and all its children
+                // Skip element and all its children.
+                return;
             }
             if REMOVE_TAG_KEEP_CHILDREN.contains(&tag) {
                 // Drop the element tag but recurse into children (content floats up).
                 for child in node.children() {
-                    serialize_node(&child, out);
+                    serialize_node(&child, out, entities, annotations.as_deref_mut());
                 }
                 return;
             }
@@ -76,28 +227,36 @@ fn serialize_node(node: &ego_tree::NodeRef<scraper::node::Node>, out: &mut Strin
                 escape_attr(val, out);
                 out.push('"');
             }
+            if let Some(a) = annotations.as_deref_mut() {
+                a.path.push(tag);
+                if let Some(val) = a.values.get(&a.path.xpath()) {
+                    out.push(' ');
+                    out.push_str(a.attr);
+                    out.push_str("=\"");
+                    escape_attr(val, out);
+                    out.push('"');
+                }
+            }
             if is_void_element(tag) {
                 out.push_str(" />");
             } else {
                 out.push('>');
                 for child in node.children() {
-                    serialize_node(&child, out);
+                    serialize_node(&child, out, entities, annotations.as_deref_mut());
                 }
                 out.push_str("</");
                 out.push_str(tag);
                 out.push('>');
             }
+            if let Some(a) = annotations {
+                a.path.pop();
+            }
         }
         Node::Text(text) => {
             // HTML-escape so that decoded entities (e.g. &lt;year&gt; decoded to <year>
-            // by the first parse) are not re-interpreted as markup in the second parse.
+            // by the parser) are not re-interpreted as markup when the output is parsed.
             for ch in text.text.chars() {
-                match ch {
-                    '&' => out.push_str("&amp;"),
-                    '<' => out.push_str("&lt;"),
-                    '>' => out.push_str("&gt;"),
-                    _ => out.push(ch),
-                }
+                push_text_char(ch, out, entities);
             }
         }
         // Skip comments and doctypes.
@@ -106,16 +265,33 @@ fn serialize_node(node: &ego_tree::NodeRef<scraper::node::Node>, out: &mut Strin
         Node::Comment(_) | Node::ProcessingInstruction(_) | Node::Doctype(_) => {}
         Node::Fragment => {
             for child in node.children() {
-                serialize_node(&child, out);
+                serialize_node(&child, out, entities, annotations.as_deref_mut());
             }
         }
     }
 }
 
+/// Write one HTML-escaped text character into `out`, as a named entity if in `entities`.
+fn push_text_char(ch: char, out: &mut String, entities: &HashMap<char, &'static str>) {
+    match ch {
+        '&' => out.push_str("&amp;"),
+        '<' => out.push_str("&lt;"),
+        '>' => out.push_str("&gt;"),
+        _ => match entities.get(&ch) {
+            Some(name) => {
+                out.push('&');
+                out.push_str(name);
+                out.push(';');
+            }
+            None => out.push(ch),
+        },
+    }
+}
+
 /// Write an HTML-escaped attribute value into `out`.
 ///
 /// Escapes `&`, `<`, `>`, and `"` so that the serialized attribute string is valid HTML
-/// and round-trips correctly through the second parse. Bare `&` is common in URL query
+/// and round-trips correctly when parsed again. Bare `&` is common in URL query
 /// strings (e.g. `href="/?a=1&b=2"`) and must be re-encoded as `&amp;`.
 fn escape_attr(val: &str, out: &mut String) {
     for ch in val.chars() {
@@ -181,7 +357,8 @@ mod tests {
     fn test_remove_head_tag() {
         let html = "<html><head><title>Title</title></head><body><p>text</p></body></html>";
         let doc = preprocess(html);
-        // scraper's HTML5 parser always adds an implicit <head>, but its contents must be gone
+        // scraper's HTML5 parser always adds an implicit <head>; it is dropped with its contents
+        assert!(!has_tag(&doc, "head"));
         assert!(
             !has_tag(&doc, "title"),
             "<title> should be removed with <head>"
@@ -244,6 +421,19 @@ mod tests {
         assert!(has_tag(&doc, "p"));
     }
 
+    #[test]
+    fn test_removal_merges_adjacent_text() {
+        let html =
+            "<html><body><p>Hello <!-- c -->wide <script>x()</script>world</p></body></html>";
+        let doc = preprocess(html);
+        let texts: Vec<&str> = doc
+            .tree
+            .nodes()
+            .filter_map(|n| n.value().as_text().map(|t| &**t))
+            .collect();
+        assert_eq!(texts, ["Hello wide world"]);
+    }
+
     #[test]
     fn test_remove_embedded_layer() {
         // <layer> is a legacy Netscape tag removed by Python's embedded=True
@@ -274,9 +464,36 @@ mod tests {
     }
 
     #[test]
-    fn test_attribute_ampersand_survives_double_parse() {
-        // Bare & in URL query strings must be re-encoded as &amp; in the serialized
-        // intermediate so it round-trips correctly through the second parse.
+    fn test_visible_text_excludes_removed_tags() {
+        let html = concat!(
+            "<html><head><title>Title</title></head><body>",
+            "<script>var hidden = 1;</script>",
+            "<p>First   paragraph</p><p>Second</p>",
+            "<form><input value=\"x\" /><button>Submit</button></form>",
+            "</body></html>"
+        );
+        let text = visible_text(html);
+        assert_eq!(text, "First paragraph\nSecond");
+        assert!(!text.contains("hidden"));
+        assert!(!text.contains("Submit"));
+        assert!(!text.contains("Title"));
+    }
+
+    #[test]
+    fn test_visible_text_with_paragraph_tags() {
+        let html = "<html><body><p>Lead</p><article-body>First</article-body>Tail</body></html>";
+        assert_eq!(visible_text(html), "Lead\nFirstTail");
+
+        let mut tags = crate::default_paragraph_tags();
+        tags.insert("article-body".to_string());
+        let config = Config::default().with_paragraph_tags(Some(tags));
+        assert_eq!(visible_text_with(html, &config), "Lead\nFirst\nTail");
+    }
+
+    #[test]
+    fn test_attribute_ampersand_survives_preprocessing() {
+        // The decoded & in a query string is copied into the cleaned tree as is; nothing
+        // is serialized and reparsed, so it can't be mangled in between.
         let html = r#"<html><body><a href="/?a=1&amp;b=2">link</a></body></html>"#;
         let doc = preprocess(html);
         let sel = scraper::Selector::parse("a").unwrap();
@@ -287,13 +504,13 @@ mod tests {
             .value()
             .attr("href")
             .unwrap();
-        assert_eq!(href, "/?a=1&b=2", "decoded & must survive the double parse");
+        assert_eq!(href, "/?a=1&b=2", "decoded & must survive preprocessing");
     }
 
     #[test]
     fn test_text_entities_not_reparsed_as_tags() {
-        // &lt;year&gt; must survive as literal text, not become a real <year> element
-        // after the double-parse in remove_tags_and_comments().
+        // &lt;year&gt; must survive as literal text, not become a real <year> element,
+        // when the text node is copied into the cleaned tree.
         let html = "<html><body><p>Use &lt;year&gt; as placeholder</p></body></html>";
         let doc = preprocess(html);
         let content = text_content(&doc);
@@ -306,4 +523,17 @@ mod tests {
             "<year> must not become a DOM element"
         );
     }
+
+    #[test]
+    fn test_clean_html_entity_handling() {
+        let html =
+            "<html><body><p title=\"x &mdash; y\">Wait &mdash; what&hellip;</p></body></html>";
+
+        let decoded = clean_html(html, EntityHandling::default());
+        assert!(decoded.contains("Wait \u{2014} what\u{2026}"));
+        assert!(!decoded.contains("&mdash;"));
+
+        let preserved = clean_html(html, EntityHandling::Preserve);
+        assert!(preserved.contains("<p title=\"x \u{2014} y\">Wait &mdash; what&hellip;</p>"));
+    }
 }
@@ -1,14 +1,19 @@
 // Port of revise_paragraph_classification() from Python jusText justext/core.py
 
+use std::cmp::Reverse;
 use std::collections::HashMap;
 
+use crate::classify::has_copyright_marker;
 use crate::paragraph::{ClassType, Paragraph};
+use crate::{Config, DistanceUnit};
 
 /// Context-sensitive revision of paragraph classifications.
 ///
 /// Assumes `classify_paragraphs` has already set `initial_class` on all paragraphs.
-/// Runs four stages in order; each stage mutates `class_type`.
-pub fn revise_paragraph_classification(paragraphs: &mut [Paragraph], max_heading_distance: usize) {
+/// Runs four stages in order; each stage mutates `class_type`. Reads
+/// `max_heading_distance`, `heading_distance_unit`, `length_metric`, and
+/// `neargood_bias` from `config`.
+pub fn revise_paragraph_classification(paragraphs: &mut [Paragraph], config: &Config) {
     // Stage 1: copy initial_class → class_type, then promote short headings near good blocks.
     for i in 0..paragraphs.len() {
         paragraphs[i].class_type = paragraphs[i].initial_class;
@@ -17,15 +22,12 @@ pub fn revise_paragraph_classification(paragraphs: &mut [Paragraph], max_heading
             continue;
         }
 
-        let mut j = i + 1;
-        let mut distance = 0;
-        while j < paragraphs.len() && distance <= max_heading_distance {
-            if paragraphs[j].class_type == ClassType::Good {
-                paragraphs[i].class_type = ClassType::NearGood;
-                break;
-            }
-            distance += paragraphs[j].text.chars().count();
-            j += 1;
+        if let Some(_distance) = good_ahead(i, paragraphs, config, |_, p| p.class_type) {
+            paragraphs[i].class_type = ClassType::NearGood;
+            debug_log!(
+                "revise stage 1 {}: short heading -> NearGood (good paragraph at distance {_distance})",
+                paragraphs[i].xpath,
+            );
         }
     }
 
@@ -35,64 +37,487 @@ pub fn revise_paragraph_classification(paragraphs: &mut [Paragraph], max_heading
         if paragraphs[i].class_type != ClassType::Short {
             continue;
         }
-        let prev = get_neighbour(i, paragraphs, true, Direction::Prev);
-        let next = get_neighbour(i, paragraphs, true, Direction::Next);
-
-        let class = if prev == ClassType::Good && next == ClassType::Good {
-            ClassType::Good
-        } else if prev == ClassType::Bad && next == ClassType::Bad {
-            ClassType::Bad
-        } else if (prev == ClassType::Bad
-            && get_neighbour(i, paragraphs, false, Direction::Prev) == ClassType::NearGood)
-            || (next == ClassType::Bad
-                && get_neighbour(i, paragraphs, false, Direction::Next) == ClassType::NearGood)
-        {
-            ClassType::Good
-        } else {
-            ClassType::Bad
-        };
-        new_classes.insert(i, class);
+        new_classes.insert(i, resolve_short(i, paragraphs));
     }
     for (i, c) in new_classes {
         paragraphs[i].class_type = c;
     }
 
     // Stage 3: classify NearGood paragraphs (NOT batched — changes apply immediately).
+    // Python resolves to Good unless both neighbours are Bad, i.e. with at least one
+    // Good neighbour; `neargood_bias` scales that requirement (0.5 → one neighbour).
+    let required_good_neighbours = 2.0 * (1.0 - config.neargood_bias.clamp(0.0, 1.0));
     for i in 0..paragraphs.len() {
         if paragraphs[i].class_type != ClassType::NearGood {
             continue;
         }
-        let prev = get_neighbour(i, paragraphs, true, Direction::Prev);
-        let next = get_neighbour(i, paragraphs, true, Direction::Next);
-        paragraphs[i].class_type = if prev == ClassType::Bad && next == ClassType::Bad {
-            ClassType::Bad
-        } else {
-            ClassType::Good
-        };
+        paragraphs[i].class_type = resolve_neargood(i, paragraphs, required_good_neighbours);
     }
 
     // Stage 4: promote non-bad headings near good blocks to Good.
     for i in 0..paragraphs.len() {
-        if !(paragraphs[i].heading
-            && paragraphs[i].class_type == ClassType::Bad
-            && paragraphs[i].initial_class != ClassType::Bad)
+        promote_heading(i, paragraphs, config);
+    }
+
+    #[cfg(feature = "log")]
+    for p in paragraphs
+        .iter()
+        .filter(|p| p.class_type != p.initial_class)
+    {
+        debug_log!(
+            "revise {}: {:?} -> {:?}",
+            p.xpath,
+            p.initial_class,
+            p.class_type
+        );
+    }
+}
+
+/// Stage 4 rule: mark heading `i` Good if it is Bad only through revision and a Good
+/// paragraph follows within `max_heading_distance`.
+fn promote_heading(i: usize, paragraphs: &mut [Paragraph], config: &Config) {
+    if !(paragraphs[i].heading
+        && paragraphs[i].class_type == ClassType::Bad
+        && paragraphs[i].initial_class != ClassType::Bad)
+    {
+        return;
+    }
+    if let Some(_distance) = good_ahead(i, paragraphs, config, |_, p| p.class_type) {
+        paragraphs[i].class_type = ClassType::Good;
+        debug_log!(
+            "revise stage 4 {}: heading -> Good (good paragraph at distance {_distance})",
+            paragraphs[i].xpath,
+        );
+    }
+}
+
+/// Distance to the first paragraph after `i` that `class_of` calls Good, if one lies
+/// within `max_heading_distance` (the heading scan of stages 1 and 4).
+fn good_ahead(
+    i: usize,
+    paragraphs: &[Paragraph],
+    config: &Config,
+    class_of: impl Fn(usize, &Paragraph) -> ClassType,
+) -> Option<usize> {
+    let mut distance = 0;
+    for (j, p) in paragraphs.iter().enumerate().skip(i + 1) {
+        if distance > config.max_heading_distance {
+            break;
+        }
+        if class_of(j, p) == ClassType::Good {
+            return Some(distance);
+        }
+        distance += heading_distance(p, config);
+    }
+    None
+}
+
+/// Stage 2 rule: the class a Short paragraph takes from its neighbours.
+fn resolve_short(i: usize, paragraphs: &[Paragraph]) -> ClassType {
+    let prev = get_neighbour(i, paragraphs, true, Direction::Prev);
+    let next = get_neighbour(i, paragraphs, true, Direction::Next);
+
+    let class = if prev == ClassType::Good && next == ClassType::Good {
+        ClassType::Good
+    } else if prev == ClassType::Bad && next == ClassType::Bad {
+        ClassType::Bad
+    } else if (prev == ClassType::Bad
+        && get_neighbour(i, paragraphs, false, Direction::Prev) == ClassType::NearGood)
+        || (next == ClassType::Bad
+            && get_neighbour(i, paragraphs, false, Direction::Next) == ClassType::NearGood)
+    {
+        ClassType::Good
+    } else {
+        ClassType::Bad
+    };
+    trace_log!(
+        "revise stage 2 {}: neighbours prev={prev:?} next={next:?} -> {class:?}",
+        paragraphs[i].xpath,
+    );
+    class
+}
+
+/// Stage 3 rule: Good if at least `required_good_neighbours` neighbours are Good.
+fn resolve_neargood(
+    i: usize,
+    paragraphs: &[Paragraph],
+    required_good_neighbours: f64,
+) -> ClassType {
+    let prev = get_neighbour(i, paragraphs, true, Direction::Prev);
+    let next = get_neighbour(i, paragraphs, true, Direction::Next);
+    let good_neighbours =
+        usize::from(prev == ClassType::Good) + usize::from(next == ClassType::Good);
+    let class = if good_neighbours as f64 >= required_good_neighbours {
+        ClassType::Good
+    } else {
+        ClassType::Bad
+    };
+    trace_log!(
+        "revise stage 3 {}: neighbours prev={prev:?} next={next:?} -> {class:?}",
+        paragraphs[i].xpath,
+    );
+    class
+}
+
+/// Set `paragraphs[index]` to `new_class` and re-resolve only the neighbours it affects.
+///
+/// For labeling tools that let a user flip one paragraph without re-running the whole
+/// revision; pass the `config` the paragraphs were revised with. Neighbour rules
+/// (stages 2 and 3) only see past a Short or NearGood paragraph up to the nearest one
+/// that was initially Good or Bad, so the window re-resolved is the run of
+/// context-dependent paragraphs between those barriers on either side of `index`.
+/// Within it, paragraphs are reset to their `initial_class` and revised again (stages 1
+/// to 3), and headings within `max_heading_distance` before the window's end get the
+/// stage 4 promotion. This gives the classes a full revision would with `new_class`
+/// as the paragraph's input class.
+///
+/// Approximations: headings before the window that a full revision promoted only
+/// because of the old classes in it are not demoted, stage 4 scans past the window
+/// see later headings' promotions, and post-revision passes such as
+/// `trim_after_copyright` are not re-applied. The overridden paragraph keeps
+/// `new_class`; its `initial_class` is left as classified.
+///
+/// # Panics
+///
+/// Panics if `index` is out of bounds.
+pub fn apply_override(
+    paragraphs: &mut [Paragraph],
+    index: usize,
+    new_class: ClassType,
+    config: &Config,
+) {
+    paragraphs[index].class_type = new_class;
+
+    let is_barrier = |p: &Paragraph| matches!(p.initial_class, ClassType::Good | ClassType::Bad);
+    let start = paragraphs[..index]
+        .iter()
+        .rposition(is_barrier)
+        .map_or(0, |k| k + 1);
+    let end = paragraphs[index + 1..]
+        .iter()
+        .position(is_barrier)
+        .map_or(paragraphs.len(), |k| index + 1 + k);
+    let window: Vec<usize> = (start..end).filter(|&i| i != index).collect();
+
+    for &i in &window {
+        paragraphs[i].class_type = paragraphs[i].initial_class;
+    }
+    let input_class = |j: usize, p: &Paragraph| {
+        if j == index {
+            new_class
+        } else {
+            p.initial_class
+        }
+    };
+    for &i in &window {
+        if paragraphs[i].heading
+            && paragraphs[i].class_type == ClassType::Short
+            && good_ahead(i, paragraphs, config, input_class).is_some()
         {
-            continue;
+            paragraphs[i].class_type = ClassType::NearGood;
         }
+    }
+    let shorts: Vec<(usize, ClassType)> = window
+        .iter()
+        .filter(|&&i| paragraphs[i].class_type == ClassType::Short)
+        .map(|&i| (i, resolve_short(i, paragraphs)))
+        .collect();
+    for (i, c) in shorts {
+        paragraphs[i].class_type = c;
+    }
+    let required_good_neighbours = 2.0 * (1.0 - config.neargood_bias.clamp(0.0, 1.0));
+    for &i in &window {
+        if paragraphs[i].class_type == ClassType::NearGood {
+            paragraphs[i].class_type = resolve_neargood(i, paragraphs, required_good_neighbours);
+        }
+    }
+
+    // Headings before the window can reach into it as long as the paragraphs between
+    // stay within the heading distance.
+    let mut reach = start;
+    let mut distance = 0;
+    while reach > 0 && distance <= config.max_heading_distance {
+        reach -= 1;
+        distance += heading_distance(&paragraphs[reach], config);
+    }
+    for i in reach..end {
+        if i != index {
+            promote_heading(i, paragraphs, config);
+        }
+    }
+}
 
-        let mut j = i + 1;
-        let mut distance = 0;
-        while j < paragraphs.len() && distance <= max_heading_distance {
-            if paragraphs[j].class_type == ClassType::Good {
-                paragraphs[i].class_type = ClassType::Good;
-                break;
+/// Promote stacked headings (e.g. an `<h1>` title directly above an `<h2>` subtitle)
+/// as a group.
+///
+/// For every run of two or more consecutive headings, if a Good paragraph lies within
+/// `max_heading_distance` after the run, each heading in the run is marked Good,
+/// except those whose `initial_class` is Bad (matching stage 4). Runs after revision.
+pub fn promote_heading_stacks(paragraphs: &mut [Paragraph], config: &Config) {
+    let mut start = 0;
+    while start < paragraphs.len() {
+        if !paragraphs[start].heading {
+            start += 1;
+            continue;
+        }
+        let end = paragraphs[start..]
+            .iter()
+            .position(|p| !p.heading)
+            .map_or(paragraphs.len(), |n| start + n);
+        if end - start >= 2 && good_within(paragraphs, end, config) {
+            for p in &mut paragraphs[start..end] {
+                if p.initial_class != ClassType::Bad {
+                    p.class_type = ClassType::Good;
+                }
             }
-            distance += paragraphs[j].text.chars().count();
-            j += 1;
         }
+        start = end;
     }
 }
 
+/// Reclassify Short-origin paragraphs by a symmetric window vote.
+///
+/// Each paragraph whose `initial_class` is Short becomes Good if strictly more than
+/// half of the paragraphs within `window` positions on either side (itself excluded)
+/// are Good, and Bad otherwise. Votes read the classes as revision left them, so the
+/// result doesn't depend on scan order. A zero window changes nothing.
+pub fn smooth_short_paragraphs(paragraphs: &mut [Paragraph], window: usize) {
+    if window == 0 {
+        return;
+    }
+    let good: Vec<bool> = paragraphs
+        .iter()
+        .map(|p| p.class_type == ClassType::Good)
+        .collect();
+    for (i, p) in paragraphs.iter_mut().enumerate() {
+        if p.initial_class != ClassType::Short {
+            continue;
+        }
+        let lo = i.saturating_sub(window);
+        let hi = (i + window + 1).min(good.len());
+        let votes = hi - lo - 1;
+        let good_votes = (lo..hi).filter(|&j| j != i && good[j]).count();
+        p.class_type = if good_votes * 2 > votes {
+            ClassType::Good
+        } else {
+            ClassType::Bad
+        };
+    }
+}
+
+/// Returns true if a Good paragraph starts within `max_heading_distance` from index `from`.
+fn good_within(paragraphs: &[Paragraph], from: usize, config: &Config) -> bool {
+    let mut distance = 0;
+    for p in &paragraphs[from..] {
+        if distance > config.max_heading_distance {
+            break;
+        }
+        if p.class_type == ClassType::Good {
+            return true;
+        }
+        distance += heading_distance(p, config);
+    }
+    false
+}
+
+/// How far a heading-promotion scan advances when it steps over `paragraph`.
+fn heading_distance(paragraph: &Paragraph, config: &Config) -> usize {
+    match config.heading_distance_unit {
+        DistanceUnit::Chars => config.length_metric.measure(&paragraph.text),
+        DistanceUnit::Paragraphs => 1,
+    }
+}
+
+/// Mark the longest paragraph that was ever Good or NearGood as Good.
+///
+/// Candidates were initially Good or NearGood, or are Good after revision. Length is
+/// `words_count`; ties go to the earliest paragraph. Does nothing if no
+/// paragraph was ever Good or NearGood.
+pub fn anchor_largest_good(paragraphs: &mut [Paragraph]) {
+    let mut anchor: Option<usize> = None;
+    for (i, p) in paragraphs.iter().enumerate() {
+        let candidate = matches!(p.initial_class, ClassType::Good | ClassType::NearGood)
+            || p.class_type == ClassType::Good;
+        if candidate && anchor.map_or(true, |a| p.words_count > paragraphs[a].words_count) {
+            anchor = Some(i);
+        }
+    }
+    if let Some(i) = anchor {
+        paragraphs[i].class_type = ClassType::Good;
+    }
+}
+
+/// Separators between the headline and the site name in a `<title>`.
+const TITLE_SEPARATORS: &[char] = &['|', '-', '\u{2013}', ':'];
+
+/// Flag the heading most likely to be the page title as `is_probably_title`.
+///
+/// Candidates are Good headings above the first Good non-heading paragraph. Comparing
+/// case-insensitively, the first candidate equal to `title_hint` or to one of its
+/// segments (split on [`TITLE_SEPARATORS`], as in "Headline | Site") wins; then the
+/// longest candidate contained in the hint; otherwise the first candidate. Flags are
+/// reset first, so at most one paragraph is flagged.
+pub fn mark_probable_title(paragraphs: &mut [Paragraph], title_hint: Option<&str>) {
+    for p in paragraphs.iter_mut() {
+        p.is_probably_title = false;
+    }
+    let top = paragraphs
+        .iter()
+        .position(|p| !p.heading && !p.is_boilerplate())
+        .unwrap_or(paragraphs.len());
+    let candidates: Vec<usize> = (0..top)
+        .filter(|&i| paragraphs[i].heading && !paragraphs[i].is_boilerplate())
+        .collect();
+    let text_of = |i: usize| paragraphs[i].text.trim().to_lowercase();
+    let title = title_hint
+        .map(|h| h.trim().to_lowercase())
+        .and_then(|hint| {
+            let segments: Vec<&str> = std::iter::once(hint.as_str())
+                .chain(hint.split(TITLE_SEPARATORS).map(str::trim))
+                .collect();
+            let exact = candidates
+                .iter()
+                .copied()
+                .find(|&i| segments.contains(&text_of(i).as_str()));
+            exact.or_else(|| {
+                candidates
+                    .iter()
+                    .copied()
+                    .filter(|&i| !text_of(i).is_empty() && hint.contains(&text_of(i)))
+                    .max_by_key(|&i| (text_of(i).len(), Reverse(i)))
+            })
+        })
+        .or_else(|| candidates.first().copied());
+    if let Some(i) = title {
+        paragraphs[i].is_probably_title = true;
+    }
+}
+
+/// Demote every paragraph after the first copyright-marked one to Bad.
+///
+/// `markers` are the extra markers from `Config::copyright_markers`. Runs after
+/// revision; the copyright paragraph itself keeps its class.
+pub fn trim_after_copyright(paragraphs: &mut [Paragraph], markers: &[String]) {
+    let Some(first) = paragraphs
+        .iter()
+        .position(|p| has_copyright_marker(&p.text, markers))
+    else {
+        return;
+    };
+    for p in &mut paragraphs[first + 1..] {
+        p.class_type = ClassType::Bad;
+    }
+}
+
+/// Demote cookie/consent banner paragraphs to Bad.
+///
+/// A paragraph is demoted when its text contains (case-insensitively) at least two
+/// distinct `phrases`, or one phrase while an adjacent paragraph also contains one;
+/// banners are usually a cluster of such blocks. A single mention of, say, "privacy
+/// policy" inside an article is left alone.
+pub fn drop_consent_banners(paragraphs: &mut [Paragraph], phrases: &[String]) {
+    let phrases: Vec<String> = phrases
+        .iter()
+        .map(|ph| ph.trim().to_lowercase())
+        .filter(|ph| !ph.is_empty())
+        .collect();
+    let matches: Vec<usize> = paragraphs
+        .iter()
+        .map(|p| {
+            let text = p.text.to_lowercase();
+            phrases
+                .iter()
+                .filter(|ph| text.contains(ph.as_str()))
+                .count()
+        })
+        .collect();
+    for i in 0..paragraphs.len() {
+        let clustered = (i > 0 && matches[i - 1] > 0) || matches.get(i + 1).is_some_and(|&m| m > 0);
+        if matches[i] >= 2 || (matches[i] == 1 && clustered) {
+            paragraphs[i].class_type = ClassType::Bad;
+        }
+    }
+}
+
+/// Punctuation ignored at the end of a trailing sentence when matching phrases.
+const TRAILING_PUNCTUATION: &[char] = &[
+    '.', '!', '?', '\u{2026}', ':', '\u{00BB}', '\u{203A}', '\u{2192}', '\u{3002}', '\u{FF01}',
+    '\u{FF1F}',
+];
+
+/// Characters that end a sentence when followed by whitespace.
+const SENTENCE_END: &[char] = &['.', '!', '?', '\u{2026}'];
+
+/// Full-width CJK terminators (`。！？`), which end a sentence even when the next one
+/// follows without a space.
+const FULL_WIDTH_SENTENCE_END: &[char] = &['\u{3002}', '\u{FF01}', '\u{FF1F}'];
+
+/// Remove trailing sentences or lines that match one of `phrases` (case-insensitive).
+///
+/// Only the last sentence (or line) of a paragraph is compared, and it must equal a
+/// phrase once trailing punctuation is ignored, so a phrase in the middle of a
+/// paragraph is never touched. Repeats while the new last sentence also matches.
+/// Phrases come from `Config::strip_trailing_phrases`. `words_count` is recomputed with
+/// `Config::word_segmentation`, and a paragraph left empty is marked Bad.
+pub fn strip_trailing_phrases(paragraphs: &mut [Paragraph], config: &Config) {
+    let phrases: Vec<String> = config
+        .strip_trailing_phrases
+        .iter()
+        .map(|ph| normalize_phrase(ph))
+        .filter(|ph| !ph.is_empty())
+        .collect();
+    if phrases.is_empty() {
+        return;
+    }
+    for p in paragraphs.iter_mut() {
+        let mut keep = p.text.len();
+        while let Some(start) = trailing_phrase_start(&p.text[..keep], &phrases) {
+            keep = p.text[..start].trim_end().len();
+        }
+        if keep == p.text.len() {
+            continue;
+        }
+        p.text.truncate(keep);
+        p.words_count = config.word_segmentation.split(&p.text).len();
+        p.chars_count_in_links = p.chars_count_in_links.min(p.text.chars().count());
+        if p.text.is_empty() {
+            p.class_type = ClassType::Bad;
+        }
+    }
+}
+
+/// Byte offset where the last sentence of `text` starts, if that sentence is one of
+/// the (already normalized) `phrases`.
+fn trailing_phrase_start(text: &str, phrases: &[String]) -> Option<usize> {
+    let core =
+        text.trim_end_matches(|c: char| c.is_whitespace() || TRAILING_PUNCTUATION.contains(&c));
+    if core.is_empty() {
+        return None;
+    }
+    let start = core
+        .char_indices()
+        .rev()
+        .find(|&(i, c)| {
+            c == '\n'
+                || FULL_WIDTH_SENTENCE_END.contains(&c)
+                || (SENTENCE_END.contains(&c)
+                    && core[i + c.len_utf8()..].starts_with(char::is_whitespace))
+        })
+        .map_or(0, |(i, c)| i + c.len_utf8());
+    let tail = normalize_phrase(&core[start..]);
+    phrases.contains(&tail).then_some(start)
+}
+
+/// Lowercase, whitespace-collapsed form of a phrase without trailing punctuation.
+fn normalize_phrase(phrase: &str) -> String {
+    phrase
+        .trim_end_matches(|c: char| c.is_whitespace() || TRAILING_PUNCTUATION.contains(&c))
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
 #[derive(Clone, Copy)]
 enum Direction {
     Prev,
@@ -132,6 +557,12 @@ mod tests {
     use super::*;
     use crate::paragraph::ClassType::*;
 
+    fn config(max_heading_distance: usize, unit: DistanceUnit) -> Config {
+        Config::default()
+            .with_max_heading_distance(max_heading_distance)
+            .with_heading_distance_unit(unit)
+    }
+
     /// Build a minimal Paragraph with the given initial_class (and class_type = initial_class).
     fn para(cf: ClassType) -> Paragraph {
         let mut p = Paragraph::new(
@@ -181,7 +612,7 @@ mod tests {
         // Stage 3 then sees NearGood with neighbor Good → Good.
         // Final result is Good — correct Python-faithful behavior.
         let mut ps = vec![para_heading(Short), para(Good)];
-        revise_paragraph_classification(&mut ps, 200);
+        revise_paragraph_classification(&mut ps, &Config::default());
         assert_eq!(ps[0].class_type, Good);
     }
 
@@ -194,32 +625,382 @@ mod tests {
             para_text(Bad, &"x".repeat(201)),
             para(Good),
         ];
-        revise_paragraph_classification(&mut ps, 200);
+        revise_paragraph_classification(&mut ps, &Config::default());
         assert_eq!(ps[0].class_type, Bad);
     }
 
     #[test]
     fn test_stage1_non_heading_short_not_promoted() {
         let mut ps = vec![para(Short), para(Good)];
-        revise_paragraph_classification(&mut ps, 200);
+        revise_paragraph_classification(&mut ps, &Config::default());
         // Short non-heading: stage 1 doesn't touch it; stage 2 classifies by neighbors
         // Neighbors: prev=Bad (edge), next=Good → mixed → check neargood proximity → Bad
         assert_eq!(ps[0].class_type, Bad);
     }
 
+    #[test]
+    fn test_heading_distance_in_paragraphs() {
+        // Two short paragraphs (28 chars) separate the heading from the good block:
+        // out of reach at distance 2 in chars, within reach at 2 paragraphs.
+        let make = || {
+            vec![
+                para_heading(Short),
+                para_text(Bad, "fourteen chars"),
+                para_text(Bad, "fourteen chars"),
+                para(Good),
+            ]
+        };
+
+        let mut ps = make();
+        revise_paragraph_classification(&mut ps, &config(2, DistanceUnit::Chars));
+        assert_eq!(ps[0].class_type, Bad);
+
+        let mut ps = make();
+        revise_paragraph_classification(&mut ps, &config(2, DistanceUnit::Paragraphs));
+        assert_eq!(ps[0].class_type, Good);
+    }
+
+    fn strip(text: &str, phrases: &[&str]) -> Paragraph {
+        let mut ps = vec![para_text(Good, text)];
+        let phrases: Vec<String> = phrases.iter().map(|s| s.to_string()).collect();
+        strip_trailing_phrases(
+            &mut ps,
+            &Config::default().with_strip_trailing_phrases(phrases),
+        );
+        ps.pop().unwrap()
+    }
+
+    #[test]
+    fn test_strip_trailing_phrases() {
+        let p = strip(
+            "The body of the article. Read more \u{00BB}",
+            &["read more"],
+        );
+        assert_eq!(p.text, "The body of the article.");
+        assert_eq!(p.words_count, 5);
+
+        let p = strip(
+            "Body text\nClick here to subscribe!",
+            &["Click here to subscribe"],
+        );
+        assert_eq!(p.text, "Body text");
+
+        let p = strip(
+            "Body. Source: Reuters. Read more.",
+            &["read more", "source: reuters"],
+        );
+        assert_eq!(p.text, "Body.");
+
+        // Full-width terminators end a sentence without a following space.
+        let p = strip("这是正文内容。阅读更多", &["阅读更多"]);
+        assert_eq!(p.text, "这是正文内容。");
+        let p = strip("这是正文内容。阅读更多！", &["阅读更多"]);
+        assert_eq!(p.text, "这是正文内容。");
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    #[test]
+    fn test_strip_trailing_phrases_keeps_word_segmentation() {
+        let config = Config::default()
+            .with_word_segmentation(crate::WordSegmentation::UnicodeWords)
+            .with_strip_trailing_phrases(vec!["read more".to_string()]);
+        let mut ps = vec![para_text(
+            Good,
+            "这是一个很长的中文段落内容 — 我们 的 了 是. Read more.",
+        )];
+        strip_trailing_phrases(&mut ps, &config);
+        assert_eq!(ps[0].text, "这是一个很长的中文段落内容 — 我们 的 了 是.");
+        assert_eq!(ps[0].words_count, 18);
+    }
+
+    #[test]
+    fn test_strip_trailing_phrases_anchored_to_end() {
+        let text = "Read more about it here. This is the rest of the body.";
+        assert_eq!(strip(text, &["read more"]).text, text);
+        // A phrase that is only the tail of the last sentence doesn't match either.
+        let text = "You should read more";
+        assert_eq!(strip(text, &["read more"]).text, text);
+    }
+
+    #[test]
+    fn test_strip_trailing_phrases_empties_paragraph() {
+        let p = strip("Read more", &["read more"]);
+        assert_eq!(p.text, "");
+        assert_eq!(p.words_count, 0);
+        assert_eq!(p.class_type, Bad);
+    }
+
+    #[test]
+    fn test_promote_heading_stacks() {
+        // With a zero distance only the subtitle (directly above the content) reaches
+        // the good paragraph; the stack rule carries the title along with it.
+        let make = || {
+            let mut h2 = para_heading(Short);
+            h2.dom_path = "body.h2".to_string();
+            let mut ps = vec![para_heading(Short), h2, para(Good)];
+            // Before revision the pipeline leaves class_type at its Short default.
+            for p in &mut ps {
+                p.class_type = Short;
+            }
+            ps
+        };
+
+        let mut ps = make();
+        revise_paragraph_classification(&mut ps, &config(0, DistanceUnit::Chars));
+        assert_eq!(ps[0].class_type, Bad);
+        assert_eq!(ps[1].class_type, Good);
+
+        let mut ps = make();
+        revise_paragraph_classification(&mut ps, &config(0, DistanceUnit::Chars));
+        promote_heading_stacks(&mut ps, &config(0, DistanceUnit::Chars));
+        assert_eq!(ps[0].class_type, Good);
+        assert_eq!(ps[1].class_type, Good);
+    }
+
+    #[test]
+    fn test_promote_heading_stacks_skips_lone_and_distant_headings() {
+        let mut ps = vec![para_heading(Short), para_text(Bad, "xxxxxxxx"), para(Good)];
+        revise_paragraph_classification(&mut ps, &config(0, DistanceUnit::Chars));
+        promote_heading_stacks(&mut ps, &config(0, DistanceUnit::Chars));
+        assert_eq!(ps[0].class_type, Bad);
+
+        let mut ps = vec![
+            para_heading(Short),
+            para_heading(Short),
+            para_text(Bad, "xxxxxxxx"),
+            para(Good),
+        ];
+        revise_paragraph_classification(&mut ps, &config(0, DistanceUnit::Chars));
+        promote_heading_stacks(&mut ps, &config(0, DistanceUnit::Chars));
+        assert_eq!(ps[0].class_type, Bad);
+        assert_eq!(ps[1].class_type, Bad);
+    }
+
+    #[test]
+    fn test_smooth_short_paragraphs() {
+        let make = || {
+            let mut short = para(Short);
+            short.class_type = Bad;
+            vec![para(Good), para(Bad), short, para(Good), para(Good)]
+        };
+
+        // Window of 2: three of the four neighbours are Good.
+        let mut ps = make();
+        smooth_short_paragraphs(&mut ps, 2);
+        assert_eq!(ps[2].class_type, Good);
+        assert_eq!(ps[1].class_type, Bad);
+
+        // Window of 1: one Good, one Bad; the tie goes to Bad.
+        let mut ps = make();
+        ps[2].class_type = Good;
+        smooth_short_paragraphs(&mut ps, 1);
+        assert_eq!(ps[2].class_type, Bad);
+    }
+
+    #[test]
+    fn test_anchor_largest_good_ties_pick_earliest() {
+        let mut ps = vec![
+            para_text(Bad, "one two three four five"),
+            para_text(NearGood, "one two three"),
+            para_text(NearGood, "four five six"),
+        ];
+        for p in &mut ps {
+            p.class_type = Bad;
+        }
+        anchor_largest_good(&mut ps);
+        let classes: Vec<ClassType> = ps.iter().map(|p| p.class_type).collect();
+        assert_eq!(classes, [Bad, Good, Bad]);
+    }
+
+    #[test]
+    fn test_mark_probable_title() {
+        let heading = |cf: ClassType, text: &str| {
+            let mut p = para_heading(cf);
+            p.text = text.to_string();
+            p
+        };
+        let mut ps = vec![
+            heading(Good, "Site name"),
+            heading(Good, "Storm floods the valley"),
+            para(Good),
+            heading(Good, "Later section"),
+        ];
+        mark_probable_title(&mut ps, Some("Storm Floods the Valley | Daily News"));
+        let flags: Vec<bool> = ps.iter().map(|p| p.is_probably_title).collect();
+        assert_eq!(flags, [false, true, false, false]);
+
+        // A short heading that merely appears in the title loses to an exact segment.
+        let mut ps = vec![
+            heading(Good, "News"),
+            heading(Good, "Storm floods the valley"),
+            para(Good),
+        ];
+        mark_probable_title(&mut ps, Some("Storm floods the valley | Daily News"));
+        let flags: Vec<bool> = ps.iter().map(|p| p.is_probably_title).collect();
+        assert_eq!(flags, [false, true, false]);
+
+        // Without an exact match, the longest heading contained in the hint wins.
+        ps[1].text = "Storm floods".to_string();
+        mark_probable_title(&mut ps, Some("Storm floods the valley - Daily News"));
+        let flags: Vec<bool> = ps.iter().map(|p| p.is_probably_title).collect();
+        assert_eq!(flags, [false, true, false]);
+
+        let mut ps = vec![
+            heading(Good, "Site name"),
+            heading(Good, "Storm floods the valley"),
+            para(Good),
+            heading(Good, "Later section"),
+        ];
+
+        // Without a matching hint the first candidate wins; headings below body text
+        // and Bad headings never qualify.
+        ps[0].class_type = Bad;
+        mark_probable_title(&mut ps, None);
+        let flags: Vec<bool> = ps.iter().map(|p| p.is_probably_title).collect();
+        assert_eq!(flags, [false, true, false, false]);
+
+        ps[1].class_type = Bad;
+        mark_probable_title(&mut ps, None);
+        assert!(ps.iter().all(|p| !p.is_probably_title));
+    }
+
+    #[test]
+    fn test_drop_consent_banners() {
+        let phrases: Vec<String> = ["we use cookies", "accept all", "privacy policy"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let mut ps = vec![
+            para_text(
+                Good,
+                "We use cookies to improve your experience. Accept all or customize.",
+            ),
+            para_text(Good, "An article that mentions our privacy policy once."),
+            para_text(Good, "Unrelated article text."),
+            para_text(Good, "Read our Privacy Policy."),
+            para_text(Good, "Click Accept All to continue."),
+        ];
+        drop_consent_banners(&mut ps, &phrases);
+        let classes: Vec<ClassType> = ps.iter().map(|p| p.class_type).collect();
+        // 0: two phrases. 1: one phrase next to a match. 2: no phrase.
+        // 3 and 4: one phrase each, clustered together.
+        assert_eq!(classes, [Bad, Bad, Good, Bad, Bad]);
+
+        let mut lone = vec![
+            para_text(Good, "Text"),
+            para_text(Good, "See the privacy policy."),
+        ];
+        drop_consent_banners(&mut lone, &phrases);
+        assert_eq!(lone[1].class_type, Good);
+    }
+
+    #[test]
+    fn test_apply_override() {
+        // [Good, Short, Bad, Short, NearGood, Bad, Short]: overriding the middle Bad to
+        // Good flips the Short paragraphs on both sides; the trailing Short lies past
+        // the next barrier and is left alone.
+        let mut ps = vec![
+            para(Good),
+            para(Short),
+            para(Bad),
+            para(Short),
+            para(NearGood),
+            para(Bad),
+            para(Short),
+        ];
+        revise_paragraph_classification(&mut ps, &Config::default());
+        let classes = |ps: &[Paragraph]| ps.iter().map(|p| p.class_type).collect::<Vec<_>>();
+        assert_eq!(classes(&ps), [Good, Bad, Bad, Bad, Bad, Bad, Bad]);
+
+        apply_override(&mut ps, 2, Good, &Config::default());
+        assert_eq!(classes(&ps), [Good, Good, Good, Good, Good, Bad, Bad]);
+
+        // Same result as a full revision with the overridden class as the input.
+        let mut full = ps.clone();
+        full[2].initial_class = Good;
+        revise_paragraph_classification(&mut full, &Config::default());
+        assert_eq!(classes(&ps), classes(&full));
+    }
+
+    #[test]
+    fn test_apply_override_matches_full_revision() {
+        // Every override on pseudo-random documents agrees with a full revision that
+        // takes the new class as input, including with a stricter `neargood_bias`.
+        let configs = [Config::default(), Config::default().with_neargood_bias(0.0)];
+        let all = [Good, Bad, Short, NearGood];
+        let mut seed: u32 = 7;
+        let mut next = move || {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            (seed >> 16) as usize % all.len()
+        };
+        for config in &configs {
+            for _ in 0..200 {
+                let doc: Vec<Paragraph> = (0..8).map(|_| para(all[next()])).collect();
+                for index in 0..doc.len() {
+                    for new_class in [Good, Bad] {
+                        let mut ps = doc.clone();
+                        revise_paragraph_classification(&mut ps, config);
+                        apply_override(&mut ps, index, new_class, config);
+
+                        let mut full = doc.clone();
+                        full[index].initial_class = new_class;
+                        revise_paragraph_classification(&mut full, config);
+                        let classes: Vec<_> = ps.iter().map(|p| p.class_type).collect();
+                        let expected: Vec<_> = full.iter().map(|p| p.class_type).collect();
+                        assert_eq!(classes, expected, "override {index} -> {new_class:?}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_apply_override_heading_distance() {
+        // The Good override lies two paragraphs past the Short heading: whether it
+        // promotes the heading depends on `max_heading_distance`.
+        let doc = vec![
+            para(Bad),
+            para_heading(Short),
+            para(Short),
+            para(Short),
+            para(Bad),
+        ];
+        let unit = Config::default().with_heading_distance_unit(DistanceUnit::Paragraphs);
+        for (distance, expected) in [
+            (0, [Bad, Bad, Bad, Good, Bad]),
+            (1, [Bad, Good, Good, Good, Bad]),
+        ] {
+            let config = unit.clone().with_max_heading_distance(distance);
+            let mut ps = doc.clone();
+            revise_paragraph_classification(&mut ps, &config);
+            apply_override(&mut ps, 3, Good, &config);
+            let classes: Vec<_> = ps.iter().map(|p| p.class_type).collect();
+            assert_eq!(classes, expected, "max_heading_distance {distance}");
+
+            let mut full = doc.clone();
+            full[3].initial_class = Good;
+            full[3].class_type = Good;
+            revise_paragraph_classification(&mut full, &config);
+            assert!(full
+                .iter()
+                .zip(&ps)
+                .all(|(f, p)| f.class_type == p.class_type));
+        }
+    }
+
     // --- Stage 2 ---
 
     #[test]
     fn test_stage2_short_between_two_good() {
         let mut ps = vec![para(Good), para(Short), para(Good)];
-        revise_paragraph_classification(&mut ps, 200);
+        revise_paragraph_classification(&mut ps, &Config::default());
         assert_eq!(ps[1].class_type, Good);
     }
 
     #[test]
     fn test_stage2_short_between_two_bad() {
         let mut ps = vec![para(Bad), para(Short), para(Bad)];
-        revise_paragraph_classification(&mut ps, 200);
+        revise_paragraph_classification(&mut ps, &Config::default());
         assert_eq!(ps[1].class_type, Bad);
     }
 
@@ -229,7 +1010,7 @@ mod tests {
         // next is Bad; check next(ignore=false): next is NearGood → Good.
         // [Good, Short, NearGood, Bad]
         let mut ps = vec![para(Good), para(Short), para(NearGood), para(Bad)];
-        revise_paragraph_classification(&mut ps, 200);
+        revise_paragraph_classification(&mut ps, &Config::default());
         assert_eq!(ps[1].class_type, Good);
     }
 
@@ -239,7 +1020,7 @@ mod tests {
         // prev is Bad; check prev(ignore=false): prev is NearGood → Good.
         // [Bad, NearGood, Short, Good]
         let mut ps = vec![para(Bad), para(NearGood), para(Short), para(Good)];
-        revise_paragraph_classification(&mut ps, 200);
+        revise_paragraph_classification(&mut ps, &Config::default());
         assert_eq!(ps[2].class_type, Good);
     }
 
@@ -251,7 +1032,7 @@ mod tests {
         // Short[1]: prev=Good, next=Bad → mixed → check neargood → no neargood → Bad
         // Short[2]: prev=Good (skips Short[1] since batch hasn't applied), next=Bad → Bad
         let mut ps = vec![para(Good), para(Short), para(Short), para(Bad)];
-        revise_paragraph_classification(&mut ps, 200);
+        revise_paragraph_classification(&mut ps, &Config::default());
         // Both resolve as Bad (no neargood neighbors)
         assert_eq!(ps[1].class_type, Bad);
         assert_eq!(ps[2].class_type, Bad);
@@ -262,14 +1043,14 @@ mod tests {
     #[test]
     fn test_stage3_neargood_both_bad_neighbors() {
         let mut ps = vec![para(Bad), para(NearGood), para(Bad)];
-        revise_paragraph_classification(&mut ps, 200);
+        revise_paragraph_classification(&mut ps, &Config::default());
         assert_eq!(ps[1].class_type, Bad);
     }
 
     #[test]
     fn test_stage3_neargood_one_good_neighbor() {
         let mut ps = vec![para(Good), para(NearGood), para(Bad)];
-        revise_paragraph_classification(&mut ps, 200);
+        revise_paragraph_classification(&mut ps, &Config::default());
         assert_eq!(ps[1].class_type, Good);
     }
 
@@ -277,10 +1058,31 @@ mod tests {
     fn test_stage3_neargood_at_document_end() {
         // NearGood at end: next neighbor = Bad (edge default)
         let mut ps = vec![para(Good), para(NearGood)];
-        revise_paragraph_classification(&mut ps, 200);
+        revise_paragraph_classification(&mut ps, &Config::default());
         assert_eq!(ps[1].class_type, Good); // prev=Good, next=Bad(edge) → not both bad → Good
     }
 
+    #[test]
+    fn test_stage3_neargood_bias_extremes() {
+        let lenient = Config::default().with_neargood_bias(1.0);
+        let strict = Config::default().with_neargood_bias(0.0);
+
+        // Both neighbours Bad: only the maximum bias rescues it.
+        let mut ps = vec![para(Bad), para(NearGood), para(Bad)];
+        revise_paragraph_classification(&mut ps, &lenient);
+        assert_eq!(ps[1].class_type, Good);
+
+        // One Good neighbour: Good by default, Bad when both are required.
+        let mut ps = vec![para(Good), para(NearGood), para(Bad)];
+        revise_paragraph_classification(&mut ps, &strict);
+        assert_eq!(ps[1].class_type, Bad);
+
+        // Both neighbours Good: Good even at the minimum bias.
+        let mut ps = vec![para(Good), para(NearGood), para(Good)];
+        revise_paragraph_classification(&mut ps, &strict);
+        assert_eq!(ps[1].class_type, Good);
+    }
+
     // --- Stage 4 ---
 
     #[test]
@@ -293,7 +1095,7 @@ mod tests {
         ];
         // Manually set up: heading cf=Short, class=Bad (simulating stage 2 made it Bad)
         ps[0].class_type = Bad;
-        revise_paragraph_classification(&mut ps, 200);
+        revise_paragraph_classification(&mut ps, &Config::default());
         // Stage 1 runs first: short heading near good → neargood, not Bad going into stage 4
         // Actually let's use cf=NearGood so stage 1 doesn't touch it
         // Rebuild: heading with cf=NearGood, class=Bad
@@ -306,7 +1108,7 @@ mod tests {
             para_text(Bad, "filler"),
             para(Good),
         ];
-        revise_paragraph_classification(&mut ps2, 200);
+        revise_paragraph_classification(&mut ps2, &Config::default());
         assert_eq!(ps2[0].class_type, Good);
     }
 
@@ -314,10 +1116,33 @@ mod tests {
     fn test_stage4_heading_cf_bad_not_promoted() {
         // Heading with initial_class=Bad stays Bad even near Good (initial_class=Bad is excluded)
         let mut ps = vec![para_heading(Bad), para(Good)];
-        revise_paragraph_classification(&mut ps, 200);
+        revise_paragraph_classification(&mut ps, &Config::default());
         assert_eq!(ps[0].class_type, Bad);
     }
 
+    // --- Copyright trimming ---
+
+    #[test]
+    fn test_trim_after_copyright() {
+        let mut ps = vec![
+            para(Good),
+            para_text(Bad, "\u{00A9} 2024 Example"),
+            para(Good),
+            para(Good),
+        ];
+        trim_after_copyright(&mut ps, &[]);
+        let classes: Vec<ClassType> = ps.iter().map(|p| p.class_type).collect();
+        assert_eq!(classes, vec![Good, Bad, Bad, Bad]);
+    }
+
+    #[test]
+    fn test_trim_after_copyright_without_marker() {
+        let mut ps = vec![para(Good), para(Bad), para(Good)];
+        trim_after_copyright(&mut ps, &[]);
+        let classes: Vec<ClassType> = ps.iter().map(|p| p.class_type).collect();
+        assert_eq!(classes, vec![Good, Bad, Good]);
+    }
+
     // --- Neighbor helper edge cases ---
 
     #[test]
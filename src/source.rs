@@ -0,0 +1,306 @@
+// Mapping paragraphs back to byte ranges of the original HTML.
+//
+// scraper doesn't record source positions, and preprocessing copies the kept nodes
+// into a fresh tree anyway, so paragraphs are located by matching their text against
+// the visible characters of the raw markup.
+
+use crate::entities::named_entity;
+use crate::paragraph::Paragraph;
+use crate::Config;
+
+/// Elements whose content never reaches a paragraph: raw-text elements and the ones
+/// the preprocessor removes.
+///
+/// `head` itself isn't listed: its end tag is optional, and the text-bearing elements
+/// inside it (`title`, `script`, `style`) are skipped on their own.
+const SKIP_CONTENT_TAGS: &[&str] = &[
+    "script", "style", "noscript", "textarea", "title", "button", "select", "object", "applet",
+    "iframe",
+];
+
+/// How many visible characters past the expected position a paragraph is searched for.
+///
+/// Bounds the cost of a paragraph that doesn't occur in the source, which would
+/// otherwise scan the rest of the document. Text the walker drops between paragraphs
+/// (anything not covered by [`SKIP_CONTENT_TAGS`]) must fit in this gap.
+const MAX_GAP: usize = 4096;
+
+/// A visible, non-whitespace character of the source and the bytes it was spelled with.
+///
+/// `ch` is `None` for a named reference this crate has no table entry for; it matches
+/// any one character.
+struct SourceChar {
+    ch: Option<char>,
+    start: usize,
+    end: usize,
+}
+
+/// Visible text of an HTML source, for locating paragraphs in document order.
+pub(crate) struct SourceMap {
+    chars: Vec<SourceChar>,
+    /// Index into `chars` just past the last located paragraph.
+    cursor: usize,
+    /// Visible characters of paragraphs passed over since the last located one, so the
+    /// next paragraph is expected this far past `cursor`.
+    skipped: usize,
+    /// Whether `^(`/`_(` markers may appear in paragraph text (`preserve_scripts`).
+    script_markers: bool,
+    /// Visible placeholder the preprocessor may have inserted.
+    placeholder: Option<char>,
+}
+
+impl SourceMap {
+    pub(crate) fn new(html: &str, config: &Config) -> Self {
+        Self {
+            chars: visible_chars(html),
+            cursor: 0,
+            skipped: 0,
+            script_markers: config.preserve_scripts,
+            placeholder: config
+                .inline_removal_placeholder
+                .filter(|c| !c.is_whitespace()),
+        }
+    }
+
+    /// Set `source_start`/`source_end` on each paragraph, in order.
+    ///
+    /// Each paragraph's non-whitespace characters are matched against the source after
+    /// the previous match, so the range runs from its first to its last visible
+    /// character and spans any markup in between. Paragraphs whose text doesn't occur
+    /// in the source keep `0..0`; those carrying text the pipeline inserted
+    /// (`preserve_scripts` markers, a visible `inline_removal_placeholder`) aren't
+    /// searched for at all. The search for each paragraph is bounded, so unmatched
+    /// paragraphs keep the whole pass linear in the document size.
+    pub(crate) fn locate(&mut self, paragraphs: &mut [Paragraph]) {
+        for paragraph in paragraphs {
+            let needle: Vec<char> = paragraph
+                .text
+                .chars()
+                .filter(|c| !c.is_whitespace())
+                .collect();
+            let inserted = self.inserted_chars(&paragraph.text);
+            if inserted > 0 {
+                self.skipped += needle.len().saturating_sub(inserted);
+                continue;
+            }
+            match self.find(&needle) {
+                Some(at) => {
+                    paragraph.source_start = self.chars[at].start;
+                    paragraph.source_end = self.chars[at + needle.len() - 1].end;
+                    self.cursor = at + needle.len();
+                    self.skipped = 0;
+                }
+                None => self.skipped += needle.len(),
+            }
+        }
+    }
+
+    /// Number of visible characters in `text` that the pipeline inserted rather than
+    /// took from the source.
+    fn inserted_chars(&self, text: &str) -> usize {
+        let mut inserted = 0;
+        if self.script_markers {
+            // Each `^(` or `_(` comes with a closing `)`.
+            inserted += 3 * (text.matches("^(").count() + text.matches("_(").count());
+        }
+        if let Some(placeholder) = self.placeholder {
+            inserted += text.matches(placeholder).count();
+        }
+        inserted
+    }
+
+    /// Index of the first match of `needle` within [`MAX_GAP`] characters of where it is
+    /// expected: `skipped` characters past the cursor.
+    fn find(&self, needle: &[char]) -> Option<usize> {
+        let first = *needle.first()?;
+        let last_start = self.chars.len().checked_sub(needle.len())?;
+        let expected = self.cursor + self.skipped;
+        let from = expected.saturating_sub(MAX_GAP).max(self.cursor);
+        let to = last_start.min(expected + MAX_GAP);
+        let matches = |sc: &SourceChar, c: char| sc.ch.map_or(true, |ch| ch == c);
+        (from..=to).find(|&i| {
+            matches(&self.chars[i], first)
+                && needle
+                    .iter()
+                    .zip(&self.chars[i..])
+                    .all(|(&c, sc)| matches(sc, c))
+        })
+    }
+}
+
+/// Decode the visible, non-whitespace characters of `html` with their byte ranges.
+fn visible_chars(html: &str) -> Vec<SourceChar> {
+    let bytes = html.as_bytes();
+    let mut chars = Vec::new();
+    let mut i = 0;
+    while i < html.len() {
+        match bytes[i] {
+            b'<' => i = skip_markup(html, i),
+            b'&' => {
+                let (ch, end) = decode_reference(html, i).unwrap_or((Some('&'), i + 1));
+                if !ch.is_some_and(char::is_whitespace) {
+                    chars.push(SourceChar { ch, start: i, end });
+                }
+                i = end;
+            }
+            _ => {
+                let ch = html[i..].chars().next().unwrap_or_default();
+                let end = i + ch.len_utf8();
+                if !ch.is_whitespace() {
+                    chars.push(SourceChar {
+                        ch: Some(ch),
+                        start: i,
+                        end,
+                    });
+                }
+                i = end;
+            }
+        }
+    }
+    chars
+}
+
+/// Byte offset just past the markup starting at `at` (a `<`), including the content
+/// of [`SKIP_CONTENT_TAGS`] elements. A `<` that doesn't start markup is skipped alone.
+fn skip_markup(html: &str, at: usize) -> usize {
+    let rest = &html[at..];
+    if rest.starts_with("<!--") {
+        return rest.find("-->").map_or(html.len(), |end| at + end + 3);
+    }
+    let starts_tag = rest[1..]
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || matches!(c, '/' | '!' | '?'));
+    if !starts_tag {
+        return at + 1;
+    }
+    let open_end = tag_end(html, at);
+    let name: String = rest[1..]
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric())
+        .collect::<String>()
+        .to_ascii_lowercase();
+    let self_closing = html[..open_end].ends_with("/>");
+    if !self_closing && SKIP_CONTENT_TAGS.contains(&name.as_str()) {
+        let close = format!("</{name}");
+        return html.as_bytes()[open_end..]
+            .windows(close.len())
+            .position(|w| w.eq_ignore_ascii_case(close.as_bytes()))
+            .map_or(html.len(), |end| tag_end(html, open_end + end));
+    }
+    open_end
+}
+
+/// Byte offset just past the `>` closing the tag at `at`, honouring quoted attributes.
+fn tag_end(html: &str, at: usize) -> usize {
+    let mut quote = None;
+    for (i, b) in html.bytes().enumerate().skip(at + 1) {
+        match (quote, b) {
+            (None, b'"' | b'\'') => quote = Some(b),
+            (Some(q), _) if b == q => quote = None,
+            (None, b'>') => return i + 1,
+            _ => {}
+        }
+    }
+    html.len()
+}
+
+/// Decode the character reference at `at` (a `&`), returning it and the offset past `;`.
+///
+/// Well-formed named references missing from the entity table decode to `None`.
+fn decode_reference(html: &str, at: usize) -> Option<(Option<char>, usize)> {
+    let rest = &html[at + 1..];
+    let semi = rest.find(';').filter(|&n| n <= 32)?;
+    let body = &rest[..semi];
+    let ch = if let Some(num) = body.strip_prefix('#') {
+        let code = match num.strip_prefix(['x', 'X']) {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => num.parse().ok()?,
+        };
+        Some(char::from_u32(code)?)
+    } else if !body.is_empty() && body.chars().all(|c| c.is_ascii_alphanumeric()) {
+        named_entity(body)
+    } else {
+        return None;
+    };
+    Some((ch, at + 1 + semi + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn visible(html: &str) -> String {
+        visible_chars(html)
+            .iter()
+            .map(|c| c.ch.unwrap_or('?'))
+            .collect()
+    }
+
+    #[test]
+    fn test_visible_chars_skip_markup() {
+        let html = "<head><title>T</title></head><!-- c --><p class=\"a>b\">x &amp; y&#x21;\
+                    <script>var s = '<p>';</script>z<button>OK</button>&eacute;</p>";
+        assert_eq!(visible(html), "x&y!z?");
+        let amp = visible_chars(html)
+            .into_iter()
+            .find(|c| c.ch == Some('&'))
+            .unwrap();
+        assert_eq!(&html[amp.start..amp.end], "&amp;");
+    }
+
+    #[test]
+    fn test_locate_in_order() {
+        let html = "<p>Home</p><nav>Home</nav><p>  Hello <b>big</b>\n world </p>";
+        let mut ps = vec![
+            Paragraph::builder().text("Home").build(),
+            Paragraph::builder().text("Home").build(),
+            Paragraph::builder().text("Hello big world").build(),
+            Paragraph::builder().text("^(missing)").build(),
+        ];
+        SourceMap::new(html, &Config::default()).locate(&mut ps);
+        let ranges: Vec<&str> = ps
+            .iter()
+            .map(|p| &html[p.source_start..p.source_end])
+            .collect();
+        assert_eq!(ranges, ["Home", "Home", "Hello <b>big</b>\n world", ""]);
+        assert_eq!(ps[1].source_start, 16);
+    }
+
+    #[test]
+    fn test_visible_chars_without_head_end_tag() {
+        let html = "<html><head><title>T</title><body><p>Hello</p></body></html>";
+        assert_eq!(visible(html), "Hello");
+    }
+
+    #[test]
+    fn test_locate_skips_inserted_text() {
+        let html = "<p>x<sup>2</sup> y</p><p>after</p>";
+        let mut ps = vec![
+            Paragraph::builder().text("x^(2) y").build(),
+            Paragraph::builder().text("after").build(),
+        ];
+        let config = Config::default().with_preserve_scripts(true);
+        SourceMap::new(html, &config).locate(&mut ps);
+        assert_eq!((ps[0].source_start, ps[0].source_end), (0, 0));
+        assert_eq!(&html[ps[1].source_start..ps[1].source_end], "after");
+    }
+
+    #[test]
+    fn test_locate_bounds_search() {
+        // A paragraph missing from the source doesn't stop later ones from matching.
+        let filler = "<p>filler text</p>".repeat(1000);
+        let html = format!("<p>one</p>{filler}<p>two</p>");
+        let mut ps = vec![
+            Paragraph::builder().text("one").build(),
+            Paragraph::builder().text("missing").build(),
+        ];
+        ps.extend((0..1000).map(|_| Paragraph::builder().text("filler text").build()));
+        ps.push(Paragraph::builder().text("two").build());
+        SourceMap::new(&html, &Config::default()).locate(&mut ps);
+        assert_eq!(&html[ps[0].source_start..ps[0].source_end], "one");
+        assert_eq!(ps[1].source_end, 0);
+        let last = ps.last().unwrap();
+        assert_eq!(&html[last.source_start..last.source_end], "two");
+    }
+}
@@ -0,0 +1,356 @@
+// Typed names for the embedded stoplists.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::JustextError;
+
+/// One of the embedded stoplist languages, for typo-proof calls to
+/// [`justext_language`](crate::justext_language) and friends.
+///
+/// [`as_str`](Self::as_str) gives the name used by [`get_stoplist`](super::get_stoplist)
+/// and [`available_languages`](super::available_languages); parsing accepts those names
+/// case-insensitively.
+///
+/// ```rust
+/// use justext::Language;
+///
+/// let language: Language = "norwegian_bokmal".parse().unwrap();
+/// assert_eq!(language, Language::NorwegianBokmal);
+/// assert_eq!(language.as_str(), "Norwegian_Bokmal");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Language {
+    Afrikaans,
+    Albanian,
+    Arabic,
+    Aragonese,
+    Armenian,
+    Aromanian,
+    Asturian,
+    Azerbaijani,
+    Basque,
+    Belarusian,
+    BelarusianTaraskievica,
+    Bengali,
+    BishnupriyaManipuri,
+    Bosnian,
+    Breton,
+    Bulgarian,
+    Catalan,
+    Cebuano,
+    Chuvash,
+    Croatian,
+    Czech,
+    Danish,
+    Dutch,
+    English,
+    Esperanto,
+    Estonian,
+    Finnish,
+    French,
+    Galician,
+    Georgian,
+    German,
+    Greek,
+    Gujarati,
+    Haitian,
+    Hebrew,
+    Hindi,
+    Hungarian,
+    Icelandic,
+    Ido,
+    Igbo,
+    Indonesian,
+    Irish,
+    Italian,
+    Javanese,
+    Kannada,
+    Kazakh,
+    Korean,
+    Kurdish,
+    Kyrgyz,
+    Latin,
+    Latvian,
+    Lithuanian,
+    Lombard,
+    LowSaxon,
+    Luxembourgish,
+    Macedonian,
+    Malay,
+    Malayalam,
+    Maltese,
+    Marathi,
+    Neapolitan,
+    Nepali,
+    Newar,
+    NorwegianBokmal,
+    NorwegianNynorsk,
+    Occitan,
+    Persian,
+    Piedmontese,
+    Polish,
+    Portuguese,
+    Quechua,
+    Romanian,
+    Russian,
+    Samogitian,
+    Serbian,
+    SerboCroatian,
+    Sicilian,
+    SimpleEnglish,
+    Slovak,
+    Slovenian,
+    Spanish,
+    Sundanese,
+    Swahili,
+    Swedish,
+    Tagalog,
+    Tamil,
+    Telugu,
+    Turkish,
+    Turkmen,
+    Ukrainian,
+    Urdu,
+    Uzbek,
+    Vietnamese,
+    Volapuk,
+    Walloon,
+    WarayWaray,
+    Welsh,
+    WestFrisian,
+    WesternPanjabi,
+    Yoruba,
+}
+
+impl Language {
+    /// Every variant, in the order of [`available_languages`](super::available_languages).
+    pub const ALL: &'static [Language] = &[
+        Language::Afrikaans,
+        Language::Albanian,
+        Language::Arabic,
+        Language::Aragonese,
+        Language::Armenian,
+        Language::Aromanian,
+        Language::Asturian,
+        Language::Azerbaijani,
+        Language::Basque,
+        Language::Belarusian,
+        Language::BelarusianTaraskievica,
+        Language::Bengali,
+        Language::BishnupriyaManipuri,
+        Language::Bosnian,
+        Language::Breton,
+        Language::Bulgarian,
+        Language::Catalan,
+        Language::Cebuano,
+        Language::Chuvash,
+        Language::Croatian,
+        Language::Czech,
+        Language::Danish,
+        Language::Dutch,
+        Language::English,
+        Language::Esperanto,
+        Language::Estonian,
+        Language::Finnish,
+        Language::French,
+        Language::Galician,
+        Language::Georgian,
+        Language::German,
+        Language::Greek,
+        Language::Gujarati,
+        Language::Haitian,
+        Language::Hebrew,
+        Language::Hindi,
+        Language::Hungarian,
+        Language::Icelandic,
+        Language::Ido,
+        Language::Igbo,
+        Language::Indonesian,
+        Language::Irish,
+        Language::Italian,
+        Language::Javanese,
+        Language::Kannada,
+        Language::Kazakh,
+        Language::Korean,
+        Language::Kurdish,
+        Language::Kyrgyz,
+        Language::Latin,
+        Language::Latvian,
+        Language::Lithuanian,
+        Language::Lombard,
+        Language::LowSaxon,
+        Language::Luxembourgish,
+        Language::Macedonian,
+        Language::Malay,
+        Language::Malayalam,
+        Language::Maltese,
+        Language::Marathi,
+        Language::Neapolitan,
+        Language::Nepali,
+        Language::Newar,
+        Language::NorwegianBokmal,
+        Language::NorwegianNynorsk,
+        Language::Occitan,
+        Language::Persian,
+        Language::Piedmontese,
+        Language::Polish,
+        Language::Portuguese,
+        Language::Quechua,
+        Language::Romanian,
+        Language::Russian,
+        Language::Samogitian,
+        Language::Serbian,
+        Language::SerboCroatian,
+        Language::Sicilian,
+        Language::SimpleEnglish,
+        Language::Slovak,
+        Language::Slovenian,
+        Language::Spanish,
+        Language::Sundanese,
+        Language::Swahili,
+        Language::Swedish,
+        Language::Tagalog,
+        Language::Tamil,
+        Language::Telugu,
+        Language::Turkish,
+        Language::Turkmen,
+        Language::Ukrainian,
+        Language::Urdu,
+        Language::Uzbek,
+        Language::Vietnamese,
+        Language::Volapuk,
+        Language::Walloon,
+        Language::WarayWaray,
+        Language::Welsh,
+        Language::WestFrisian,
+        Language::WesternPanjabi,
+        Language::Yoruba,
+    ];
+
+    /// The stoplist name, e.g. `"Norwegian_Bokmal"`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Language::Afrikaans => "Afrikaans",
+            Language::Albanian => "Albanian",
+            Language::Arabic => "Arabic",
+            Language::Aragonese => "Aragonese",
+            Language::Armenian => "Armenian",
+            Language::Aromanian => "Aromanian",
+            Language::Asturian => "Asturian",
+            Language::Azerbaijani => "Azerbaijani",
+            Language::Basque => "Basque",
+            Language::Belarusian => "Belarusian",
+            Language::BelarusianTaraskievica => "Belarusian_Taraskievica",
+            Language::Bengali => "Bengali",
+            Language::BishnupriyaManipuri => "Bishnupriya_Manipuri",
+            Language::Bosnian => "Bosnian",
+            Language::Breton => "Breton",
+            Language::Bulgarian => "Bulgarian",
+            Language::Catalan => "Catalan",
+            Language::Cebuano => "Cebuano",
+            Language::Chuvash => "Chuvash",
+            Language::Croatian => "Croatian",
+            Language::Czech => "Czech",
+            Language::Danish => "Danish",
+            Language::Dutch => "Dutch",
+            Language::English => "English",
+            Language::Esperanto => "Esperanto",
+            Language::Estonian => "Estonian",
+            Language::Finnish => "Finnish",
+            Language::French => "French",
+            Language::Galician => "Galician",
+            Language::Georgian => "Georgian",
+            Language::German => "German",
+            Language::Greek => "Greek",
+            Language::Gujarati => "Gujarati",
+            Language::Haitian => "Haitian",
+            Language::Hebrew => "Hebrew",
+            Language::Hindi => "Hindi",
+            Language::Hungarian => "Hungarian",
+            Language::Icelandic => "Icelandic",
+            Language::Ido => "Ido",
+            Language::Igbo => "Igbo",
+            Language::Indonesian => "Indonesian",
+            Language::Irish => "Irish",
+            Language::Italian => "Italian",
+            Language::Javanese => "Javanese",
+            Language::Kannada => "Kannada",
+            Language::Kazakh => "Kazakh",
+            Language::Korean => "Korean",
+            Language::Kurdish => "Kurdish",
+            Language::Kyrgyz => "Kyrgyz",
+            Language::Latin => "Latin",
+            Language::Latvian => "Latvian",
+            Language::Lithuanian => "Lithuanian",
+            Language::Lombard => "Lombard",
+            Language::LowSaxon => "Low_Saxon",
+            Language::Luxembourgish => "Luxembourgish",
+            Language::Macedonian => "Macedonian",
+            Language::Malay => "Malay",
+            Language::Malayalam => "Malayalam",
+            Language::Maltese => "Maltese",
+            Language::Marathi => "Marathi",
+            Language::Neapolitan => "Neapolitan",
+            Language::Nepali => "Nepali",
+            Language::Newar => "Newar",
+            Language::NorwegianBokmal => "Norwegian_Bokmal",
+            Language::NorwegianNynorsk => "Norwegian_Nynorsk",
+            Language::Occitan => "Occitan",
+            Language::Persian => "Persian",
+            Language::Piedmontese => "Piedmontese",
+            Language::Polish => "Polish",
+            Language::Portuguese => "Portuguese",
+            Language::Quechua => "Quechua",
+            Language::Romanian => "Romanian",
+            Language::Russian => "Russian",
+            Language::Samogitian => "Samogitian",
+            Language::Serbian => "Serbian",
+            Language::SerboCroatian => "Serbo_Croatian",
+            Language::Sicilian => "Sicilian",
+            Language::SimpleEnglish => "Simple_English",
+            Language::Slovak => "Slovak",
+            Language::Slovenian => "Slovenian",
+            Language::Spanish => "Spanish",
+            Language::Sundanese => "Sundanese",
+            Language::Swahili => "Swahili",
+            Language::Swedish => "Swedish",
+            Language::Tagalog => "Tagalog",
+            Language::Tamil => "Tamil",
+            Language::Telugu => "Telugu",
+            Language::Turkish => "Turkish",
+            Language::Turkmen => "Turkmen",
+            Language::Ukrainian => "Ukrainian",
+            Language::Urdu => "Urdu",
+            Language::Uzbek => "Uzbek",
+            Language::Vietnamese => "Vietnamese",
+            Language::Volapuk => "Volapuk",
+            Language::Walloon => "Walloon",
+            Language::WarayWaray => "Waray_Waray",
+            Language::Welsh => "Welsh",
+            Language::WestFrisian => "West_Frisian",
+            Language::WesternPanjabi => "Western_Panjabi",
+            Language::Yoruba => "Yoruba",
+        }
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Language {
+    type Err = JustextError;
+
+    /// Parse a stoplist name, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Language::ALL
+            .iter()
+            .copied()
+            .find(|language| language.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| JustextError::UnknownLanguage(s.to_string()))
+    }
+}
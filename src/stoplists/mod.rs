@@ -1,147 +1,113 @@
-use std::collections::HashSet;
-use std::sync::LazyLock;
+use std::collections::{HashMap, HashSet};
+use std::sync::{LazyLock, RwLock};
 
-/// All embedded stoplists: (language_name, file_contents).
-const STOPLISTS: &[(&str, &str)] = &[
-    ("Afrikaans", include_str!("Afrikaans.txt")),
-    ("Albanian", include_str!("Albanian.txt")),
-    ("Arabic", include_str!("Arabic.txt")),
-    ("Aragonese", include_str!("Aragonese.txt")),
-    ("Armenian", include_str!("Armenian.txt")),
-    ("Aromanian", include_str!("Aromanian.txt")),
-    ("Asturian", include_str!("Asturian.txt")),
-    ("Azerbaijani", include_str!("Azerbaijani.txt")),
-    ("Basque", include_str!("Basque.txt")),
-    ("Belarusian", include_str!("Belarusian.txt")),
-    (
-        "Belarusian_Taraskievica",
-        include_str!("Belarusian_Taraskievica.txt"),
-    ),
-    ("Bengali", include_str!("Bengali.txt")),
-    (
-        "Bishnupriya_Manipuri",
-        include_str!("Bishnupriya_Manipuri.txt"),
-    ),
-    ("Bosnian", include_str!("Bosnian.txt")),
-    ("Breton", include_str!("Breton.txt")),
-    ("Bulgarian", include_str!("Bulgarian.txt")),
-    ("Catalan", include_str!("Catalan.txt")),
-    ("Cebuano", include_str!("Cebuano.txt")),
-    ("Chuvash", include_str!("Chuvash.txt")),
-    ("Croatian", include_str!("Croatian.txt")),
-    ("Czech", include_str!("Czech.txt")),
-    ("Danish", include_str!("Danish.txt")),
-    ("Dutch", include_str!("Dutch.txt")),
-    ("English", include_str!("English.txt")),
-    ("Esperanto", include_str!("Esperanto.txt")),
-    ("Estonian", include_str!("Estonian.txt")),
-    ("Finnish", include_str!("Finnish.txt")),
-    ("French", include_str!("French.txt")),
-    ("Galician", include_str!("Galician.txt")),
-    ("Georgian", include_str!("Georgian.txt")),
-    ("German", include_str!("German.txt")),
-    ("Greek", include_str!("Greek.txt")),
-    ("Gujarati", include_str!("Gujarati.txt")),
-    ("Haitian", include_str!("Haitian.txt")),
-    ("Hebrew", include_str!("Hebrew.txt")),
-    ("Hindi", include_str!("Hindi.txt")),
-    ("Hungarian", include_str!("Hungarian.txt")),
-    ("Icelandic", include_str!("Icelandic.txt")),
-    ("Ido", include_str!("Ido.txt")),
-    ("Igbo", include_str!("Igbo.txt")),
-    ("Indonesian", include_str!("Indonesian.txt")),
-    ("Irish", include_str!("Irish.txt")),
-    ("Italian", include_str!("Italian.txt")),
-    ("Javanese", include_str!("Javanese.txt")),
-    ("Kannada", include_str!("Kannada.txt")),
-    ("Kazakh", include_str!("Kazakh.txt")),
-    ("Korean", include_str!("Korean.txt")),
-    ("Kurdish", include_str!("Kurdish.txt")),
-    ("Kyrgyz", include_str!("Kyrgyz.txt")),
-    ("Latin", include_str!("Latin.txt")),
-    ("Latvian", include_str!("Latvian.txt")),
-    ("Lithuanian", include_str!("Lithuanian.txt")),
-    ("Lombard", include_str!("Lombard.txt")),
-    ("Low_Saxon", include_str!("Low_Saxon.txt")),
-    ("Luxembourgish", include_str!("Luxembourgish.txt")),
-    ("Macedonian", include_str!("Macedonian.txt")),
-    ("Malay", include_str!("Malay.txt")),
-    ("Malayalam", include_str!("Malayalam.txt")),
-    ("Maltese", include_str!("Maltese.txt")),
-    ("Marathi", include_str!("Marathi.txt")),
-    ("Neapolitan", include_str!("Neapolitan.txt")),
-    ("Nepali", include_str!("Nepali.txt")),
-    ("Newar", include_str!("Newar.txt")),
-    ("Norwegian_Bokmal", include_str!("Norwegian_Bokmal.txt")),
-    ("Norwegian_Nynorsk", include_str!("Norwegian_Nynorsk.txt")),
-    ("Occitan", include_str!("Occitan.txt")),
-    ("Persian", include_str!("Persian.txt")),
-    ("Piedmontese", include_str!("Piedmontese.txt")),
-    ("Polish", include_str!("Polish.txt")),
-    ("Portuguese", include_str!("Portuguese.txt")),
-    ("Quechua", include_str!("Quechua.txt")),
-    ("Romanian", include_str!("Romanian.txt")),
-    ("Russian", include_str!("Russian.txt")),
-    ("Samogitian", include_str!("Samogitian.txt")),
-    ("Serbian", include_str!("Serbian.txt")),
-    ("Serbo_Croatian", include_str!("Serbo_Croatian.txt")),
-    ("Sicilian", include_str!("Sicilian.txt")),
-    ("Simple_English", include_str!("Simple_English.txt")),
-    ("Slovak", include_str!("Slovak.txt")),
-    ("Slovenian", include_str!("Slovenian.txt")),
-    ("Spanish", include_str!("Spanish.txt")),
-    ("Sundanese", include_str!("Sundanese.txt")),
-    ("Swahili", include_str!("Swahili.txt")),
-    ("Swedish", include_str!("Swedish.txt")),
-    ("Tagalog", include_str!("Tagalog.txt")),
-    ("Tamil", include_str!("Tamil.txt")),
-    ("Telugu", include_str!("Telugu.txt")),
-    ("Turkish", include_str!("Turkish.txt")),
-    ("Turkmen", include_str!("Turkmen.txt")),
-    ("Ukrainian", include_str!("Ukrainian.txt")),
-    ("Urdu", include_str!("Urdu.txt")),
-    ("Uzbek", include_str!("Uzbek.txt")),
-    ("Vietnamese", include_str!("Vietnamese.txt")),
-    ("Volapuk", include_str!("Volapuk.txt")),
-    ("Walloon", include_str!("Walloon.txt")),
-    ("Waray_Waray", include_str!("Waray_Waray.txt")),
-    ("Welsh", include_str!("Welsh.txt")),
-    ("West_Frisian", include_str!("West_Frisian.txt")),
-    ("Western_Panjabi", include_str!("Western_Panjabi.txt")),
-    ("Yoruba", include_str!("Yoruba.txt")),
-];
+mod language;
+
+pub use language::Language;
+
+// Generated by build.rs: `STOPLISTS`, every embedded stoplist as a `phf::Set`, in
+// name order.
+include!(concat!(env!("OUT_DIR"), "/stoplists.rs"));
 
 /// Merged set of all stopwords from every language, cached.
 static ALL_STOPLISTS: LazyLock<HashSet<String>> = LazyLock::new(|| {
-    let mut set = HashSet::new();
-    for (_, contents) in STOPLISTS {
-        for word in parse_stoplist(contents) {
-            set.insert(word);
-        }
-    }
-    set
+    STOPLISTS
+        .iter()
+        .flat_map(|(_, words)| words.iter())
+        .map(|word| word.to_string())
+        .collect()
 });
 
-/// Parse a stoplist file: one word per line, lowercased, blank lines skipped.
-fn parse_stoplist(contents: &str) -> HashSet<String> {
-    contents
-        .lines()
-        .map(|line| line.trim())
-        .filter(|line| !line.is_empty())
+/// Minimum share of a page's words that must be stopwords of the best language for
+/// [`detect_language`] to report it.
+const MIN_LANGUAGE_MATCH_RATIO: f64 = 0.2;
+
+/// Stoplists added at runtime with [`register_stoplist`], keyed by lowercased name.
+static REGISTERED: LazyLock<RwLock<HashMap<String, HashSet<String>>>> =
+    LazyLock::new(Default::default);
+
+/// Register a stoplist under `language`, for [`get_stoplist`] and every `*_lang` function.
+///
+/// Names match case-insensitively, and a registered list takes precedence over an
+/// embedded one of the same name, so this can both add languages and replace built-in
+/// lists. Words are trimmed and lowercased, and blank entries dropped, exactly as for
+/// the embedded files. Registering a name again replaces the earlier list. Registered
+/// lists are process-wide; they don't appear in [`available_languages`] or
+/// [`get_all_stoplists`].
+pub fn register_stoplist(language: &str, words: HashSet<String>) {
+    let words = words
+        .iter()
+        .map(|word| word.trim())
+        .filter(|word| !word.is_empty())
         .map(|word| word.to_lowercase())
-        .collect()
+        .collect();
+    REGISTERED
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(language.to_lowercase(), words);
+}
+
+/// Copyright phrases used by a language's sites, beyond the universal `©` (case-insensitive
+/// match on the language name).
+///
+/// Returns an empty slice for languages without extra markers, including English.
+pub fn copyright_markers(language: &str) -> &'static [&'static str] {
+    match language.to_lowercase().as_str() {
+        "chinese" => &["版权所有", "版權所有"],
+        "japanese" => &["無断転載禁止", "無断転載を禁じます"],
+        "korean" => &["무단 전재", "무단전재"],
+        _ => &[],
+    }
 }
 
 /// Return the stoplist for a given language (case-insensitive match).
 ///
+/// Lists added with [`register_stoplist`] are consulted before the embedded ones.
+/// Embedded lists are copied out of their compiled-in set, with no parsing; use
+/// [`get_stoplist_set`] to avoid the allocation altogether.
 /// Returns `Err(JustextError::UnknownLanguage)` if the language is not recognized.
 pub fn get_stoplist(language: &str) -> Result<HashSet<String>, crate::error::JustextError> {
+    let language_lower = language.to_lowercase();
+    let registered = REGISTERED
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(&language_lower)
+        .cloned();
+    if let Some(words) = registered {
+        return Ok(words);
+    }
+    get_stoplist_set(language)
+        .map(|words| words.iter().map(|word| word.to_string()).collect())
+        .ok_or_else(|| crate::error::JustextError::UnknownLanguage(language.to_string()))
+}
+
+/// Return the embedded stoplist for `language` (case-insensitive match) without
+/// allocating.
+///
+/// The sets are compiled in at build time. Unlike [`get_stoplist`], lists added with
+/// [`register_stoplist`] are not consulted; returns `None` for names without an
+/// embedded list.
+pub fn get_stoplist_set(language: &str) -> Option<&'static phf::Set<&'static str>> {
     let language_lower = language.to_lowercase();
     STOPLISTS
         .iter()
         .find(|(name, _)| name.to_lowercase() == language_lower)
-        .map(|(_, contents)| parse_stoplist(contents))
-        .ok_or_else(|| crate::error::JustextError::UnknownLanguage(language.to_string()))
+        .map(|(_, words)| *words)
+}
+
+/// Return the union of the stoplists for `languages`, e.g. `&["English", "Spanish"]` for
+/// a bilingual site.
+///
+/// Each name is looked up as in [`get_stoplist`]; the first unknown one is returned as
+/// `Err(JustextError::UnknownLanguage)`.
+pub fn get_merged_stoplist(
+    languages: &[&str],
+) -> Result<HashSet<String>, crate::error::JustextError> {
+    let mut merged = HashSet::new();
+    for language in languages {
+        merged.extend(get_stoplist(language)?);
+    }
+    Ok(merged)
 }
 
 /// Return the merged set of all stopwords from every language.
@@ -153,3 +119,42 @@ pub fn get_all_stoplists() -> &'static HashSet<String> {
 pub fn available_languages() -> Vec<&'static str> {
     STOPLISTS.iter().map(|(name, _)| *name).collect()
 }
+
+/// Guess the language of a page from its visible text.
+///
+/// Each word of [`visible_text`](crate::visible_text) is lowercased and stripped of
+/// leading and trailing punctuation, then every embedded stoplist is scored by the share
+/// of words it contains. Returns the best-scoring language, or `None` if the page has no
+/// words or no language reaches a 20% match ratio. Lists added with
+/// [`register_stoplist`] are not considered.
+pub fn detect_language(html: &str) -> Option<&'static str> {
+    let text = crate::visible_text(html);
+    let words: Vec<String> = text
+        .split_whitespace()
+        .map(|w| {
+            w.trim_matches(|c: char| !c.is_alphanumeric())
+                .to_lowercase()
+        })
+        .filter(|w| !w.is_empty())
+        .collect();
+    if words.is_empty() {
+        return None;
+    }
+    let (language, matches) = STOPLISTS
+        .iter()
+        .map(|(name, stoplist)| {
+            (
+                *name,
+                words
+                    .iter()
+                    .filter(|w| stoplist.contains(w.as_str()))
+                    .count(),
+            )
+        })
+        .fold(None, |best: Option<(&str, usize)>, candidate| match best {
+            Some(b) if b.1 >= candidate.1 => Some(b),
+            _ => Some(candidate),
+        })?;
+    let ratio = matches as f64 / words.len() as f64;
+    (ratio >= MIN_LANGUAGE_MATCH_RATIO).then_some(language)
+}
@@ -0,0 +1,120 @@
+// Sentence splitting for extracted text.
+
+use std::collections::HashSet;
+
+use crate::{justext, Config};
+
+/// Abbreviations (lowercase, without the final period) that don't end a sentence.
+const ABBREVIATIONS: &[&str] = &[
+    "dr", "mr", "mrs", "ms", "prof", "sr", "jr", "st", "mt", "vs", "etc", "e.g", "i.e", "inc",
+    "ltd", "co", "corp", "no", "fig", "gen", "gov", "sen", "rep", "rev", "approx",
+];
+
+/// Characters that may follow a terminator before the whitespace (`."`, `?)`).
+const CLOSERS: &[char] = &['"', '\'', ')', ']', '\u{201D}', '\u{2019}', '\u{00BB}'];
+
+/// Extract the good paragraphs and split them into sentences.
+///
+/// A sentence ends at `.`, `!`, or `?` (optionally followed by closing quotes or
+/// brackets) when whitespace and then an uppercase letter follow. A period doesn't
+/// end a sentence after a common abbreviation ("Dr.", "e.g.") or a single-letter
+/// initial ("J. Smith"), and decimals like "3.14" are never split. Sentences never
+/// span paragraphs. Whitespace is trimmed and empty sentences are dropped.
+pub fn extract_sentences(html: &str, stoplist: &HashSet<String>, config: &Config) -> Vec<String> {
+    justext(html, stoplist, config)
+        .iter()
+        .filter(|p| !p.is_boilerplate())
+        .flat_map(|p| split_sentences(&p.text))
+        .collect()
+}
+
+/// Split `text` into sentences; see [`extract_sentences`] for the rules.
+fn split_sentences(text: &str) -> Vec<String> {
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    for (i, &(pos, c)) in chars.iter().enumerate() {
+        if !matches!(c, '.' | '!' | '?') {
+            continue;
+        }
+        let mut j = i + 1;
+        while j < chars.len() && CLOSERS.contains(&chars[j].1) {
+            j += 1;
+        }
+        let end = chars.get(j).map_or(text.len(), |&(p, _)| p);
+        if j >= chars.len() || !chars[j].1.is_whitespace() {
+            continue;
+        }
+        let next = chars[j..]
+            .iter()
+            .map(|&(_, c)| c)
+            .find(|c| !c.is_whitespace());
+        if !next.is_some_and(|n| n.is_uppercase() || CLOSERS.contains(&n) || n == '\u{201C}') {
+            continue;
+        }
+        if c == '.' && is_abbreviation(&text[start..pos]) {
+            continue;
+        }
+        push_sentence(&text[start..end], &mut sentences);
+        start = end;
+    }
+    push_sentence(&text[start..], &mut sentences);
+    sentences
+}
+
+/// Returns true if the word right before a period is an abbreviation or an initial.
+fn is_abbreviation(before: &str) -> bool {
+    let word = before
+        .rsplit(|c: char| c.is_whitespace() || c == '(' || c == '"')
+        .next()
+        .unwrap_or("");
+    let mut letters = word.chars();
+    let single_initial =
+        matches!((letters.next(), letters.next()), (Some(c), None) if c.is_uppercase());
+    single_initial || ABBREVIATIONS.contains(&word.to_lowercase().as_str())
+}
+
+fn push_sentence(sentence: &str, out: &mut Vec<String>) {
+    let sentence = sentence.trim();
+    if !sentence.is_empty() {
+        out.push(sentence.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_basic() {
+        assert_eq!(
+            split_sentences("It rained. Then it stopped! Did it? Yes."),
+            ["It rained.", "Then it stopped!", "Did it?", "Yes."]
+        );
+    }
+
+    #[test]
+    fn test_split_keeps_decimals_and_abbreviations() {
+        assert_eq!(
+            split_sentences("Pi is about 3.14 in value. Dr. Smith met J. Doe at 5 p.m. on Monday. They talked, e.g. About pi."),
+            [
+                "Pi is about 3.14 in value.",
+                "Dr. Smith met J. Doe at 5 p.m. on Monday.",
+                "They talked, e.g. About pi."
+            ]
+        );
+    }
+
+    #[test]
+    fn test_split_requires_capital_and_handles_closers() {
+        assert_eq!(
+            split_sentences("He said \"stop.\" Then left. see lowercase. Éclair time."),
+            [
+                "He said \"stop.\"",
+                "Then left. see lowercase.",
+                "Éclair time."
+            ]
+        );
+        assert!(split_sentences("   ").is_empty());
+    }
+}
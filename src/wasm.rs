@@ -0,0 +1,67 @@
+// wasm-bindgen entry point for calling justext from JavaScript without uniffi.
+
+use wasm_bindgen::prelude::*;
+
+use crate::{justext_lang, Config};
+
+/// Classify `html` and return the paragraphs as a JSON array.
+///
+/// `config_json` is a [`Config`] object as read by [`Config::from_json`], e.g.
+/// `{"length_low": 50}`; an empty string means the defaults. Paragraphs serialize as
+/// with the `serde` feature. An unknown language or a malformed or invalid config throws
+/// a JavaScript `Error`.
+#[wasm_bindgen]
+pub fn classify_paragraphs_json(
+    html: &str,
+    language: &str,
+    config_json: &str,
+) -> Result<String, JsValue> {
+    classify_json(html, language, config_json).map_err(|e| JsError::new(&e).into())
+}
+
+/// [`classify_paragraphs_json`] with the error as a plain message, so it runs natively.
+fn classify_json(html: &str, language: &str, config_json: &str) -> Result<String, String> {
+    let config = if config_json.trim().is_empty() {
+        Config::default()
+    } else {
+        Config::from_json(config_json).map_err(|e| e.to_string())?
+    };
+    let paragraphs = justext_lang(html, language, &config).map_err(|e| e.to_string())?;
+    serde_json::to_string(&paragraphs).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_json() {
+        let html = "<html><body><p>Quarterly revenue figures</p></body></html>";
+        let json = classify_json(html, "English", "").unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value[0]["text"], "Quarterly revenue figures");
+        assert_eq!(value[0]["initial_class"], "short");
+
+        // Below length_low the text is Short; once it is long enough it is judged on
+        // stopword density, which makes it Bad.
+        let json = classify_json(html, "English", r#"{"length_low": 5}"#).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value[0]["initial_class"], "bad");
+    }
+
+    #[test]
+    fn test_classify_json_errors() {
+        let html = "<html><body><p>Quarterly revenue figures</p></body></html>";
+        assert_eq!(
+            classify_json(html, "Klingon", "").unwrap_err(),
+            "unknown language: Klingon"
+        );
+        assert!(classify_json(html, "English", r#"{"length_lo": 5}"#)
+            .unwrap_err()
+            .starts_with("invalid config JSON"));
+        assert_eq!(
+            classify_json(html, "English", r#"{"length_low": 500}"#).unwrap_err(),
+            "length_low (500) exceeds length_high (200)"
+        );
+    }
+}
@@ -44,6 +44,19 @@ class JustextTest {
         }
     }
 
+    @Nested inner class ExtractSections {
+
+        @Test fun `sections with levels`() {
+            val html = "<html><body><p>$goodParagraph</p>" +
+                "<h2>Background</h2><p>$goodParagraph</p>" +
+                "<h3>Details</h3><p>$goodParagraph</p></body></html>"
+            val sections = extractSections(html, "English")
+            assertEquals(listOf(null, "Background", "Details"), sections.map { it.heading })
+            assertEquals(listOf(0L, 2L, 3L), sections.map { it.level })
+            assertTrue(sections.all { it.paragraphs == listOf(goodParagraph) })
+        }
+    }
+
     @Nested inner class ClassifyParagraphs {
 
         @Test fun `good paragraph`() {
@@ -76,6 +89,21 @@ class JustextTest {
             assertTrue(h.heading)
             assertEquals(2L, h.wordCount)
         }
+
+        @Test fun `paragraph densities`() {
+            val html = "<html><body><p>$goodParagraph</p><p><a>Home</a> | <a>About</a></p></body></html>"
+            val paragraphs = classifyParagraphs(html, "English")
+            assertTrue(paragraphs[0].stopwordDensity >= 0.32)
+            assertEquals(0.0, paragraphs[0].linkDensity, 0.0)
+            assertTrue(paragraphs[1].linkDensity > 0.5)
+        }
+
+        @Test fun `class type codes are stable`() {
+            assertEquals(0, classTypeCode(ClassType.GOOD))
+            assertEquals(1, classTypeCode(ClassType.BAD))
+            assertEquals(2, classTypeCode(ClassType.SHORT))
+            assertEquals(3, classTypeCode(ClassType.NEAR_GOOD))
+        }
     }
 
     @Nested inner class ConfigAndLanguages {
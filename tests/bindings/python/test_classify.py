@@ -5,6 +5,7 @@ import pytest
 from justext_uniffi import (
     ClassType,
     JustextError,
+    class_type_code,
     classify_paragraphs,
     classify_paragraphs_with,
     default_config,
@@ -55,6 +56,14 @@ def test_paragraph_fields():
     assert isinstance(h.tag_count, int)
 
 
+def test_paragraph_densities():
+    html = f"<html><body><p>{GOOD_PARAGRAPH}</p><p><a>Home</a> | <a>About</a></p></body></html>"
+    good, links = classify_paragraphs(html, "English")
+    assert 0.32 <= good.stopword_density <= 1.0
+    assert good.link_density == 0.0
+    assert links.link_density > 0.5
+
+
 def test_classify_with_custom_config():
     """With lowered length thresholds, a short paragraph should not be classified as SHORT."""
     config = default_config()
@@ -64,3 +73,10 @@ def test_classify_with_custom_config():
     paragraphs = classify_paragraphs_with(html, "English", config)
     assert len(paragraphs) > 0
     assert paragraphs[0].class_type != ClassType.SHORT
+
+
+def test_class_type_codes_are_stable():
+    assert class_type_code(ClassType.GOOD) == 0
+    assert class_type_code(ClassType.BAD) == 1
+    assert class_type_code(ClassType.SHORT) == 2
+    assert class_type_code(ClassType.NEAR_GOOD) == 3
@@ -4,6 +4,7 @@ import pytest
 
 from justext_uniffi import (
     JustextError,
+    extract_sections,
     extract_text,
     extract_text_with,
     default_config,
@@ -46,3 +47,18 @@ def test_extract_text_filters_boilerplate():
     result = extract_text(html, "English")
     assert "good content" in result
     assert "Home" not in result
+
+
+def test_extract_sections():
+    html = (
+        f"<html><body><p>{GOOD_PARAGRAPH}</p>"
+        f"<h2>Background</h2><p>{GOOD_PARAGRAPH}</p>"
+        f"<h3>Details</h3><p>{GOOD_PARAGRAPH}</p></body></html>"
+    )
+    sections = extract_sections(html, "English")
+    assert [(s.heading, s.level) for s in sections] == [
+        (None, 0),
+        ("Background", 2),
+        ("Details", 3),
+    ]
+    assert all(s.paragraphs == [GOOD_PARAGRAPH] for s in sections)
@@ -50,6 +50,18 @@ final class JustextTests: XCTestCase {
         XCTAssertFalse(result.contains("Home"))
     }
 
+    // MARK: - extract_sections()
+
+    func testExtractSections() throws {
+        let html = "<html><body><p>\(goodParagraph)</p>"
+            + "<h2>Background</h2><p>\(goodParagraph)</p>"
+            + "<h3>Details</h3><p>\(goodParagraph)</p></body></html>"
+        let sections = try extractSections(html: html, language: "English")
+        XCTAssertEqual(sections.map { $0.heading }, [nil, "Background", "Details"])
+        XCTAssertEqual(sections.map { $0.level }, [0, 2, 3])
+        XCTAssertTrue(sections.allSatisfy { $0.paragraphs == [goodParagraph] })
+    }
+
     // MARK: - classify_paragraphs()
 
     func testClassifyGoodParagraph() throws {
@@ -83,6 +95,21 @@ final class JustextTests: XCTestCase {
         XCTAssertEqual(h.wordCount, 2)
     }
 
+    func testParagraphDensities() throws {
+        let html = "<html><body><p>\(goodParagraph)</p><p><a>Home</a> | <a>About</a></p></body></html>"
+        let paragraphs = try classifyParagraphs(html: html, language: "English")
+        XCTAssertGreaterThanOrEqual(paragraphs[0].stopwordDensity, 0.32)
+        XCTAssertEqual(paragraphs[0].linkDensity, 0.0)
+        XCTAssertGreaterThan(paragraphs[1].linkDensity, 0.5)
+    }
+
+    func testClassTypeCodesAreStable() {
+        XCTAssertEqual(classTypeCode(ct: .good), 0)
+        XCTAssertEqual(classTypeCode(ct: .bad), 1)
+        XCTAssertEqual(classTypeCode(ct: .short), 2)
+        XCTAssertEqual(classTypeCode(ct: .nearGood), 3)
+    }
+
     // MARK: - default config & languages
 
     func testDefaultConfig() {
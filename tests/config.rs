@@ -0,0 +1,146 @@
+// Config validation through Config::builder().
+
+use justext::{Config, ConfigBuilder, ConfigError};
+
+#[test]
+fn test_builder_defaults_are_valid() {
+    let config = Config::builder().build().unwrap();
+    assert_eq!(config.length_low, Config::default().length_low);
+    assert_eq!(Config::default().validate(), Ok(()));
+}
+
+#[test]
+fn test_builder_sets_fields() {
+    let config = Config::builder()
+        .length_low(10)
+        .length_high(10)
+        .stopwords_low(0.2)
+        .stopwords_high(0.2)
+        .max_link_density(1.0)
+        .max_heading_distance(3)
+        .no_headings(true)
+        .neargood_bias(0.0)
+        .max_uppercase_ratio(Some(0.8))
+        .min_alpha_ratio(Some(0.5))
+        .build()
+        .unwrap();
+    assert_eq!((config.length_low, config.length_high), (10, 10));
+    assert_eq!((config.stopwords_low, config.stopwords_high), (0.2, 0.2));
+    assert_eq!(config.max_link_density, 1.0);
+    assert_eq!(config.max_heading_distance, 3);
+    assert!(config.no_headings);
+    assert_eq!(config.neargood_bias, 0.0);
+    assert_eq!(config.max_uppercase_ratio, Some(0.8));
+    assert_eq!(config.min_alpha_ratio, Some(0.5));
+}
+
+#[test]
+fn test_builder_rejects_length_low_above_high() {
+    let err = Config::builder()
+        .length_low(300)
+        .length_high(200)
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ConfigError::LengthRange {
+            low: 300,
+            high: 200
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "length_low (300) exceeds length_high (200)"
+    );
+}
+
+#[test]
+fn test_builder_rejects_stopwords_low_above_high() {
+    let err = Config::builder()
+        .stopwords_low(0.5)
+        .stopwords_high(0.4)
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ConfigError::StopwordsRange {
+            low: 0.5,
+            high: 0.4
+        }
+    );
+}
+
+#[test]
+fn test_builder_rejects_densities_out_of_range() {
+    let cases = [
+        (Config::builder().stopwords_low(-0.1), "stopwords_low", -0.1),
+        (Config::builder().stopwords_high(1.2), "stopwords_high", 1.2),
+        (
+            Config::builder().max_link_density(1.5),
+            "max_link_density",
+            1.5,
+        ),
+        (Config::builder().neargood_bias(2.0), "neargood_bias", 2.0),
+        (
+            Config::builder().max_uppercase_ratio(Some(1.1)),
+            "max_uppercase_ratio",
+            1.1,
+        ),
+        (
+            Config::builder().min_alpha_ratio(Some(-1.0)),
+            "min_alpha_ratio",
+            -1.0,
+        ),
+    ];
+    for (builder, field, value) in cases {
+        assert_eq!(
+            builder.build().unwrap_err(),
+            ConfigError::OutOfRange { field, value }
+        );
+    }
+}
+
+#[test]
+fn test_validate_position_edge_fraction() {
+    assert!(Config::default()
+        .with_position_edge_fraction(0.5)
+        .validate()
+        .is_ok());
+    for value in [0.6, -0.1] {
+        assert_eq!(
+            Config::default()
+                .with_position_edge_fraction(value)
+                .validate()
+                .unwrap_err(),
+            ConfigError::EdgeFraction { value }
+        );
+    }
+    assert!(matches!(
+        Config::default()
+            .with_position_edge_fraction(f64::NAN)
+            .validate(),
+        Err(ConfigError::EdgeFraction { .. })
+    ));
+}
+
+#[test]
+fn test_builder_rejects_nan() {
+    let err = Config::builder()
+        .max_link_density(f64::NAN)
+        .build()
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        ConfigError::OutOfRange {
+            field: "max_link_density",
+            ..
+        }
+    ));
+}
+
+#[test]
+fn test_builder_from_config_validates_with_chain() {
+    let config = Config::default().with_length_low(500);
+    let err = ConfigBuilder::from(config).build().unwrap_err();
+    assert!(matches!(err, ConfigError::LengthRange { low: 500, .. }));
+}
@@ -0,0 +1,60 @@
+// Runtime stoplist registration. Registration is process-wide, so these tests live in
+// their own binary to keep "Klingon" unknown everywhere else.
+
+use std::collections::HashSet;
+
+use justext::{
+    extract_by_language, extract_text_lang, get_stoplist, justext, justext_lang, register_stoplist,
+    ClassReason, Config,
+};
+
+fn words(list: &[&str]) -> HashSet<String> {
+    list.iter().map(|w| w.to_string()).collect()
+}
+
+#[test]
+fn test_register_new_language() {
+    let text = "qaStaH nuq ghaH vaj qaStaH DaH ghaH vaj jIyajbe' ghaH nuq DaH vaj qaStaH \
+                ghaH vaj nuq DaH jIyajbe' ghaH qaStaH vaj nuq DaH ghaH vaj qaStaH nuq DaH \
+                ghaH vaj jIyajbe' nuq qaStaH ghaH vaj DaH nuq ghaH vaj qaStaH nuq DaH ghaH.";
+    let html = format!("<html><body><p>{text}</p></body></html>");
+    assert!(extract_text_lang(&html, "Klingon", &Config::default()).is_err());
+
+    register_stoplist("Klingon", words(&["ghaH", "vaj", "  nuq ", "DaH", ""]));
+
+    let stoplist = get_stoplist("KLINGON").unwrap();
+    assert_eq!(stoplist, words(&["ghah", "vaj", "nuq", "dah"]));
+    assert_eq!(
+        extract_text_lang(&html, "klingon", &Config::default()).unwrap(),
+        text
+    );
+}
+
+#[test]
+fn test_register_overrides_embedded_list() {
+    register_stoplist("Latin", words(&["lex", "ius"]));
+    assert_eq!(get_stoplist("latin").unwrap(), words(&["lex", "ius"]));
+}
+
+#[test]
+fn test_language_copyright_markers() {
+    // No Chinese stoplist is embedded; register one so `justext_lang` accepts the name.
+    register_stoplist("Chinese", words(&["的", "了", "是"]));
+    let html = "<html><body><p>版权所有 2024 新华网 保留所有权利</p></body></html>";
+
+    let ps = justext_lang(html, "Chinese", &Config::default()).unwrap();
+    assert_eq!(ps[0].reason, Some(ClassReason::CopyrightMarker));
+
+    // The markers come from the language name; the English defaults don't know them.
+    let ps = justext(html, &get_stoplist("Chinese").unwrap(), &Config::default());
+    assert_ne!(ps[0].reason, Some(ClassReason::CopyrightMarker));
+    let ps = justext_lang(html, "English", &Config::default()).unwrap();
+    assert_ne!(ps[0].reason, Some(ClassReason::CopyrightMarker));
+
+    // Buckets are classified with the language's markers too.
+    let html = "<html><body><p>新闻 的 了 是 的 了 是 内容</p>\
+                <p>版权所有 的 了 是 的 了 是 新华网</p></body></html>";
+    let config = Config::default().with_length_low(5).with_length_high(10);
+    let buckets = extract_by_language(html, &["Chinese"], &config).unwrap();
+    assert_eq!(buckets["Chinese"], "新闻 的 了 是 的 了 是 内容");
+}
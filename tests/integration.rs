@@ -1,12 +1,29 @@
 // Integration tests: end-to-end HTML → classified paragraphs
 // Ports test_core.py and provides additional real-world coverage.
 
-use justext::{extract_text, get_stoplist, justext, ClassType, Config};
+use justext::{
+    annotate_html, boilerplate_path_histogram, classify_and_revise, classify_articles,
+    content_hash, document_score, extract_above_fold, extract_all, extract_article,
+    extract_by_language, extract_by_percentile, extract_canonical, extract_capped,
+    extract_filtered, extract_if_changed, extract_markdown, extract_sections, extract_sentences,
+    extract_sorted_by_xpath, extract_text, extract_text_gated, extract_text_if_substantial,
+    extract_with_stats, get_stoplist, good_paragraphs, group_by_container, justext,
+    justext_for_each, justext_fragments, justext_from_html, justext_lang_with,
+    justext_with_inner_html, justext_within, reclassify_diff, ClassType, Config, JustextError,
+    Paragraph, Section, UNKNOWN_LANGUAGE,
+};
 
 fn english() -> std::collections::HashSet<String> {
     get_stoplist("English").unwrap()
 }
 
+/// Over 200 chars (`length_high`) with a high stopword density, so it is classified
+/// directly as Good (not just NearGood) without needing neighbor context.
+const GOOD: &str = "This is a sentence that contains many common English stopwords and it \
+                    should be classified as good content by the algorithm because the text is \
+                    long enough that it exceeds the length_high threshold of two hundred \
+                    characters.";
+
 // --- Port of test_core.py ---
 
 #[test]
@@ -31,13 +48,7 @@ fn test_empty_html() {
 
 #[test]
 fn test_single_good_paragraph() {
-    // Paragraph must be >200 chars with high stopword density to be classified
-    // directly as Good (not just NearGood) without needing neighbor context.
-    let text = "This is a sentence that contains many common English stopwords and it \
-                should be classified as good content by the algorithm because the text is \
-                long enough that it exceeds the length_high threshold of two hundred characters.";
-    assert!(text.len() > 200, "test text must exceed length_high=200");
-    let html = format!("<html><body><p>{text}</p></body></html>");
+    let html = format!("<html><body><p>{GOOD}</p></body></html>");
     let ps = justext(&html, &english(), &Config::default());
     assert!(!ps.is_empty());
     assert_eq!(ps[0].class_type, ClassType::Good);
@@ -114,15 +125,10 @@ fn test_copyright_paragraph_is_bad() {
 
 #[test]
 fn test_heading_near_content_is_promoted() {
-    // Content paragraph must be >200 chars with high stopwords to be Good without neighbors.
-    let content = "This paragraph contains many common English stopwords and it is long \
-                   enough to be classified as good content with the English stoplist applied \
-                   correctly by the justext algorithm when processing this article text here.";
-    assert!(content.len() > 200);
     let html = format!(
         "<html><body>\
          <h1>Article Title</h1>\
-         <p>{content}</p>\
+         <p>{GOOD}</p>\
          </body></html>"
     );
     let ps = justext(&html, &english(), &Config::default());
@@ -137,6 +143,21 @@ fn test_heading_near_content_is_promoted() {
     );
 }
 
+#[test]
+fn test_skip_revision_keeps_initial_class() {
+    let html = format!("<html><body><h1>Article Title</h1><p>{GOOD}</p></body></html>");
+
+    let ps = justext(
+        &html,
+        &english(),
+        &Config::default().with_skip_revision(true),
+    );
+    assert_eq!(ps[0].initial_class, ClassType::Short);
+    assert_eq!(ps[0].class_type, ClassType::Short);
+    assert_eq!(ps[1].class_type, ClassType::Good);
+    assert!(ps.iter().all(|p| p.class_type == p.initial_class));
+}
+
 #[test]
 fn test_paragraph_struct_fields() {
     let html = "<html><body><h2>My Heading</h2></body></html>";
@@ -148,3 +169,1149 @@ fn test_paragraph_struct_fields() {
     assert!(h.xpath.contains("h2"), "xpath should contain h2");
     assert!(h.heading, "h2 paragraph should have heading=true");
 }
+
+#[test]
+fn test_split_plain_text_on_blank_lines() {
+    // A page with no block tags is one paragraph by default. Splitting on blank
+    // lines lets each block be classified on its own.
+    let html = format!("<html><body>Menu\n\n{GOOD}\n\n{GOOD}</body></html>");
+
+    let ps = justext(&html, &english(), &Config::default());
+    assert_eq!(ps.len(), 1);
+
+    let config = Config::default().with_split_plain_text_on_blank_lines(true);
+    let ps = justext(&html, &english(), &config);
+    let texts: Vec<&str> = ps.iter().map(|p| p.text.as_str()).collect();
+    assert_eq!(texts, vec!["Menu", GOOD, GOOD]);
+    assert_eq!(ps[1].class_type, ClassType::Good);
+    assert_eq!(ps[2].class_type, ClassType::Good);
+}
+
+#[test]
+fn test_extract_filtered_drops_uppercase_paragraphs() {
+    let shouting = GOOD.to_uppercase();
+    let html = format!("<html><body><div><p>{GOOD}</p><p>{shouting}</p></div></body></html>");
+
+    let all = extract_filtered(&html, &english(), &Config::default(), |_| true);
+    assert_eq!(all.len(), 2);
+
+    let kept = extract_filtered(&html, &english(), &Config::default(), |p| {
+        assert_eq!(p.dom_path, "html.body.div.p");
+        p.text != p.text.to_uppercase()
+    });
+    assert_eq!(kept.len(), 1);
+    assert_eq!(kept[0].text, GOOD);
+}
+
+#[test]
+fn test_classify_and_revise_matches_pipeline() {
+    let html = format!(
+        "<html><body><h1>Article Title</h1><p>{GOOD}</p><p><a>Home</a> | <a>About</a></p></body></html>"
+    );
+    let expected = justext(&html, &english(), &Config::default());
+
+    let mut manual = vec![
+        Paragraph::new(
+            "html.body.h1".to_string(),
+            "/html[1]/body[1]/h1[1]".to_string(),
+            "Article Title".to_string(),
+            0,
+            0,
+        ),
+        Paragraph::new(
+            "html.body.p".to_string(),
+            "/html[1]/body[1]/p[1]".to_string(),
+            GOOD.to_string(),
+            0,
+            0,
+        ),
+        Paragraph::new(
+            "html.body.p".to_string(),
+            "/html[1]/body[1]/p[2]".to_string(),
+            "Home | About".to_string(),
+            9,
+            2,
+        ),
+    ];
+    classify_and_revise(&mut manual, &english(), &Config::default());
+
+    assert_eq!(manual.len(), expected.len());
+    for (m, e) in manual.iter().zip(&expected) {
+        assert_eq!(m.text, e.text);
+        assert_eq!(m.initial_class, e.initial_class);
+        assert_eq!(m.class_type, e.class_type);
+        assert_eq!(m.heading, e.heading);
+        assert_eq!(m.chars_count_in_links, e.chars_count_in_links);
+        assert_eq!(m.tags_count, e.tags_count);
+    }
+    assert_eq!(manual[0].class_type, ClassType::Good);
+    assert_eq!(manual[2].class_type, ClassType::Bad);
+}
+
+#[test]
+fn test_paragraph_builder() {
+    let text = "I am top-inline\nand  I am happy";
+    let p = Paragraph::builder()
+        .dom_path("html.body.div.p")
+        .xpath("/html[1]/body[1]/div[1]/p[2]")
+        .text(text)
+        .chars_count_in_links(6)
+        .tags_count(2)
+        .build();
+    assert_eq!(p.dom_path, "html.body.div.p");
+    assert_eq!(p.xpath, "/html[1]/body[1]/div[1]/p[2]");
+    assert_eq!(p.text, text);
+    assert_eq!(p.chars_count_in_links, 6);
+    assert_eq!(p.tags_count, 2);
+    assert_eq!(p.words_count, 7);
+    assert_eq!(p.class_type, ClassType::Short);
+    assert_eq!(p.initial_class, ClassType::Short);
+    assert!(!p.heading);
+
+    // words_count is derived the same way the pipeline derives it.
+    let html = "<html><body><p>I am <b>top</b>-inline\n\n and  I am happy</p></body></html>";
+    let parsed = &justext(html, &english(), &Config::default())[0];
+    let built = Paragraph::builder().text(parsed.text.clone()).build();
+    assert_eq!(built.words_count, parsed.words_count);
+}
+
+#[test]
+fn test_trim_after_copyright() {
+    let html = format!(
+        "<html><body><p>{GOOD}</p><p>\u{00A9} 2024 Example Corp.</p><p>{GOOD}</p></body></html>"
+    );
+
+    let ps = justext(&html, &english(), &Config::default());
+    assert_eq!(ps[2].class_type, ClassType::Good);
+
+    let config = Config::default().with_trim_after_copyright(true);
+    let ps = justext(&html, &english(), &config);
+    assert_eq!(ps[0].class_type, ClassType::Good);
+    assert_eq!(ps[1].class_type, ClassType::Bad);
+    assert_eq!(ps[2].class_type, ClassType::Bad);
+}
+
+#[test]
+fn test_content_hash_ignores_boilerplate_churn() {
+    let page_a = format!("<html><body><p><a>Home</a> | <a>News</a></p><p>{GOOD}</p></body></html>");
+    let page_b = format!(
+        "<html><body><p><a>Sports</a> | <a>Weather</a> | <a>Deals</a></p>\
+         <p>{GOOD}</p><p><a>Ad: buy now</a></p></body></html>"
+    );
+    let page_c = format!("<html><body><p>{GOOD} And one more sentence.</p></body></html>");
+
+    let config = Config::default();
+    let hash_a = content_hash(&page_a, &english(), &config);
+    assert_eq!(hash_a, content_hash(&page_b, &english(), &config));
+    assert_ne!(hash_a, content_hash(&page_c, &english(), &config));
+}
+
+#[test]
+fn test_group_by_container_separates_sibling_divs() {
+    let html = "<html><body>\
+                <div><p>First paragraph.</p><p>Second paragraph.</p></div>\
+                <div><p>Third paragraph.</p></div>\
+                </body></html>";
+    let ps = justext(html, &english(), &Config::default());
+    let groups = group_by_container(&ps, 3);
+
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[0].0, "/html[1]/body[1]/div[1]");
+    let texts: Vec<&str> = groups[0].1.iter().map(|p| p.text.as_str()).collect();
+    assert_eq!(texts, ["First paragraph.", "Second paragraph."]);
+    assert_eq!(groups[1].0, "/html[1]/body[1]/div[2]");
+    assert_eq!(groups[1].1.len(), 1);
+    assert_eq!(groups[1].1[0].text, "Third paragraph.");
+}
+
+#[test]
+fn test_extract_by_percentile_keeps_longer_good_paragraphs() {
+    let sentence = "This is a sentence with the words that we want to have in it. ";
+    let html = format!(
+        "<html><body><p>{}</p><p>{}</p><p>{}</p><p>{}</p></body></html>",
+        sentence.repeat(4),
+        sentence,
+        sentence.repeat(3),
+        sentence.repeat(2),
+    );
+    let config = Config::default().with_length_low(10).with_length_high(20);
+
+    let all = extract_by_percentile(&html, &english(), &config, 0.0);
+    assert_eq!(all.len(), 4);
+
+    let top = extract_by_percentile(&html, &english(), &config, 0.5);
+    let counts: Vec<usize> = top.iter().map(|p| p.words_count).collect();
+    assert_eq!(counts, [56, 42, 28]);
+
+    let longest = extract_by_percentile(&html, &english(), &config, 1.0);
+    assert_eq!(longest.len(), 1);
+    assert_eq!(longest[0].words_count, 56);
+}
+
+#[test]
+fn test_extract_by_percentile_single_good_paragraph() {
+    let html = "<html><body><p>This is a sentence with the words that we want to have in it.</p></body></html>";
+    let config = Config::default().with_length_low(10).with_length_high(20);
+    for pct in [0.0, 0.5, 1.0] {
+        assert_eq!(
+            extract_by_percentile(html, &english(), &config, pct).len(),
+            1
+        );
+    }
+    assert!(extract_by_percentile("", &english(), &config, 0.5).is_empty());
+}
+
+#[test]
+fn test_justext_lang_with_matches_justext() {
+    let html = format!("<html><body><p><a>Home</a> | <a>About</a></p><p>{GOOD}</p></body></html>");
+    let stoplist = english();
+    let config = Config::default();
+    let expected = justext(&html, &stoplist, &config);
+    let actual = justext_lang_with(&html, &stoplist, &config);
+
+    assert_eq!(actual.len(), expected.len());
+    for (a, e) in actual.iter().zip(&expected) {
+        assert_eq!(a.xpath, e.xpath);
+        assert_eq!(a.text, e.text);
+        assert_eq!(a.class_type, e.class_type);
+    }
+}
+
+#[test]
+fn test_strip_trailing_phrases_keeps_body() {
+    let html = format!("<html><body><p>{GOOD} <a href=\"/more\">Read more</a></p></body></html>");
+    let config = Config::default().with_strip_trailing_phrases(vec!["Read more".to_string()]);
+
+    let ps = justext(&html, &english(), &config);
+    assert_eq!(ps[0].class_type, ClassType::Good);
+    assert_eq!(ps[0].text, GOOD);
+    assert_eq!(ps[0].words_count, GOOD.split_whitespace().count());
+
+    let ps = justext(&html, &english(), &Config::default());
+    assert!(ps[0].text.ends_with("Read more"));
+}
+
+#[test]
+fn test_justext_for_each_matches_justext() {
+    let html = format!(
+        "<html><body><h1>Title</h1><p><a>Home</a> | <a>About</a></p><p>{GOOD}</p></body></html>"
+    );
+    let config = Config::default();
+    let mut collected = Vec::new();
+    justext_for_each(&html, &english(), &config, |p| collected.push(p.clone()));
+    assert_eq!(collected, justext(&html, &english(), &config));
+}
+
+#[test]
+fn test_good_paragraphs_matches_extract_text() {
+    let html = format!(
+        "<html><body><h1>Title</h1><p><a>Home</a> | <a>About</a></p>\
+         <p>{GOOD}</p><p>Short</p><p>{GOOD}</p></body></html>"
+    );
+    let config = Config::default();
+    let texts: Vec<String> = good_paragraphs(&html, &english(), &config)
+        .map(|p| p.text)
+        .collect();
+    assert!(texts.contains(&GOOD.to_string()));
+    assert_eq!(
+        texts,
+        extract_text(&html, &english(), &config)
+            .split('\n')
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_justext_from_html_matches_justext() {
+    let html = format!(
+        "<html><body><form><p><a>Home</a> | <a>About</a></p></form>\
+         <p>{GOOD}</p><p>Short</p><p>{GOOD}</p></body></html>"
+    );
+    let doc = scraper::Html::parse_document(&html);
+    let classes = |ps: Vec<justext::Paragraph>| {
+        ps.into_iter()
+            .map(|p| (p.xpath, p.class_type))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        classes(justext_from_html(&doc, &english(), &Config::default())),
+        classes(justext(&html, &english(), &Config::default()))
+    );
+}
+
+#[test]
+fn test_justext_with_inner_html_keeps_inline_markup() {
+    let html = "<html><body><div><p>This is a sentence that contains <em>many common</em> \
+                English stopwords and it should be classified as good content by the \
+                algorithm because the text is long enough that it exceeds the \
+                <a href=\"/docs\">length_high</a> threshold of two hundred characters.\
+                <script>track()</script></p></div><p><a href=\"/\">Home</a></p></body></html>";
+    let doc = scraper::Html::parse_document(html);
+    let good = justext_with_inner_html(&doc, &english(), &Config::default());
+    assert_eq!(good.len(), 1);
+    let (paragraph, inner) = &good[0];
+    assert_eq!(paragraph.xpath, "/html[1]/body[1]/div[1]/p[1]");
+    assert!(inner.starts_with("This is a sentence that contains <em>many common</em> English"));
+    assert!(inner.contains(r#"<a href="/docs">length_high</a>"#));
+    assert!(!inner.contains("script"));
+}
+
+#[test]
+fn test_extract_with_stats() {
+    let html = format!(
+        "<html><body><p><a>Home</a> | <a>About</a></p>\
+         <p>{GOOD}</p><p>Short</p><p>{GOOD}</p></body></html>"
+    );
+    let config = Config::default();
+    let (text, stats) = extract_with_stats(&html, &english(), &config);
+    assert_eq!(text, extract_text(&html, &english(), &config));
+    assert_eq!(stats.total, 4);
+    assert_eq!(
+        stats.good + stats.bad + stats.short + stats.near_good,
+        stats.total
+    );
+    assert_eq!((stats.good, stats.bad), (3, 1));
+    // Only the nav paragraph has links: 9 of its 12 characters.
+    assert!((stats.avg_link_density - 0.75 / 4.0).abs() < 1e-9);
+
+    let (text, stats) = extract_with_stats("", &english(), &config);
+    assert_eq!(text, "");
+    assert_eq!(stats.total, 0);
+    assert_eq!(stats.avg_link_density, 0.0);
+}
+
+#[test]
+fn test_extract_text_if_substantial() {
+    let nav = "<html><body><ul><li><a href=\"/\">Home</a></li><li><a href=\"/news\">News</a></li>\
+               <li><a href=\"/about\">About</a></li></ul></body></html>";
+    let config = Config::default();
+    assert_eq!(
+        extract_text_if_substantial(nav, "English", &config, 20).unwrap(),
+        None
+    );
+    let article = format!("<html><body><p>{GOOD}</p></body></html>");
+    assert_eq!(
+        extract_text_if_substantial(&article, "English", &config, 20).unwrap(),
+        Some(GOOD.to_string())
+    );
+    let words = GOOD.split_whitespace().count();
+    assert!(
+        extract_text_if_substantial(&article, "English", &config, words)
+            .unwrap()
+            .is_some()
+    );
+    assert!(
+        extract_text_if_substantial(&article, "English", &config, words + 1)
+            .unwrap()
+            .is_none()
+    );
+    assert!(extract_text_if_substantial(&article, "Klingon", &config, 1).is_err());
+}
+
+#[test]
+fn test_extract_text_gated() {
+    let nav: String = ["Home", "News", "Sport", "Weather", "About", "Contact"]
+        .iter()
+        .map(|s| format!("<li><a href=\"/\">{s}</a></li>"))
+        .collect();
+    // One good paragraph out of seven: ratio ~0.14.
+    let html = format!("<html><body><ul>{nav}</ul><p>{GOOD}</p></body></html>");
+    let config = Config::default();
+    assert_eq!(
+        extract_text_gated(&html, "English", &config, 0.3).unwrap(),
+        None
+    );
+    assert_eq!(
+        extract_text_gated(&html, "English", &config, 0.1).unwrap(),
+        Some(GOOD.to_string())
+    );
+    assert_eq!(
+        extract_text_gated("<html><body></body></html>", "English", &config, 0.0).unwrap(),
+        Some(String::new())
+    );
+}
+
+#[test]
+fn test_extract_capped() {
+    let html = format!(
+        "<html><body>{}</body></html>",
+        (1..=5)
+            .map(|i| format!("<p>{i}. {GOOD}</p>"))
+            .collect::<String>()
+    );
+    let config = Config::default();
+
+    let capped = extract_capped(&html, &english(), &config, 2);
+    assert_eq!(capped.len(), 2);
+    assert!(capped[0].text.starts_with("1. "));
+    assert!(capped[1].text.starts_with("2. "));
+
+    assert!(extract_capped(&html, &english(), &config, 0).is_empty());
+    assert_eq!(extract_capped(&html, &english(), &config, 10).len(), 5);
+}
+
+#[test]
+fn test_justext_fragments_revises_across_boundaries() {
+    let header = "<header><h1>Article Title</h1></header>";
+    let body = format!("<html><body><p>{GOOD}</p></body></html>");
+    let footer = "<footer><p><a href=\"/\">Home</a></p></footer>";
+
+    let ps = justext_fragments(&[header, &body, footer], &english(), &Config::default());
+    let texts: Vec<&str> = ps.iter().map(|p| p.text.as_str()).collect();
+    assert_eq!(texts, ["Article Title", GOOD, "Home"]);
+    assert_eq!(ps[0].initial_class, ClassType::Short);
+    assert_eq!(ps[0].class_type, ClassType::Good);
+    assert_eq!(ps[1].class_type, ClassType::Good);
+    assert_eq!(ps[2].class_type, ClassType::Bad);
+
+    // Classified alone, the header fragment has no content to be promoted by.
+    let alone = justext(header, &english(), &Config::default());
+    assert_eq!(alone[0].class_type, ClassType::Bad);
+}
+
+#[test]
+fn test_extract_article_reader_mode() {
+    let html = format!(
+        "<html><head><title>Site | Big Story</title></head><body>\
+         <nav><a href=\"/\">Home</a> <a href=\"/news\">News</a></nav>\
+         <h1>Big Story</h1>\
+         <p class=\"byline\">By Jane Doe</p>\
+         <p>{GOOD}</p>\
+         <p>{GOOD}</p>\
+         <footer><p>\u{00A9} 2024 Example Corp.</p></footer>\
+         </body></html>"
+    );
+    let article = extract_article(&html, "English", &Config::default()).unwrap();
+    assert_eq!(article.title.as_deref(), Some("Big Story"));
+    assert_eq!(article.byline.as_deref(), Some("Jane Doe"));
+    assert_eq!(article.content_text, format!("{GOOD}\n{GOOD}"));
+    assert_eq!(
+        article.content_html,
+        format!("<p>{GOOD}</p>\n<p>{GOOD}</p>\n")
+    );
+
+    let empty = extract_article("", "English", &Config::default()).unwrap();
+    assert_eq!(empty.title, None);
+    assert_eq!(empty.byline, None);
+    assert!(empty.content_text.is_empty());
+    assert!(empty.content_html.is_empty());
+    assert!(extract_article("", "Klingon", &Config::default()).is_err());
+}
+
+#[test]
+fn test_promote_heading_stacks() {
+    let html = format!(
+        "<html><body><h1>Big Title</h1><h2>A subtitle that explains the story</h2>\
+         <p>{GOOD}</p></body></html>"
+    );
+    let config = Config::default().with_max_heading_distance(10);
+
+    let ps = justext(&html, &english(), &config);
+    assert_eq!(ps[0].class_type, ClassType::Bad);
+    assert_eq!(ps[1].class_type, ClassType::Good);
+
+    let ps = justext(&html, &english(), &config.with_promote_heading_stacks(true));
+    assert_eq!(ps[0].class_type, ClassType::Good);
+    assert_eq!(ps[1].class_type, ClassType::Good);
+    assert_eq!(ps[2].class_type, ClassType::Good);
+}
+
+#[test]
+fn test_classify_articles_scopes_revision() {
+    let html = format!(
+        "<html><body><nav><a href=\"/\">Home</a></nav>\
+         <article><h2>First post</h2><p>{GOOD}</p></article>\
+         <article><h2>Second post</h2><p>Short teaser.</p></article>\
+         </body></html>"
+    );
+    let groups = classify_articles(&html, &english(), &Config::default());
+    assert_eq!(groups.len(), 2);
+
+    let texts: Vec<&str> = groups[0].iter().map(|p| p.text.as_str()).collect();
+    assert_eq!(texts, ["First post", GOOD]);
+    assert_eq!(groups[0][0].xpath, "/html[1]/body[1]/article[1]/h2[1]");
+    assert_eq!(groups[0][0].class_type, ClassType::Good);
+
+    // Without the first post's content as a neighbour, the second post stays boilerplate.
+    let texts: Vec<&str> = groups[1].iter().map(|p| p.text.as_str()).collect();
+    assert_eq!(texts, ["Second post", "Short teaser."]);
+    assert_eq!(groups[1][0].xpath, "/html[1]/body[1]/article[2]/h2[1]");
+    assert!(groups[1].iter().all(|p| p.is_boilerplate()));
+
+    let plain = format!("<html><body><p>{GOOD}</p></body></html>");
+    let groups = classify_articles(&plain, &english(), &Config::default());
+    assert_eq!(
+        groups,
+        vec![justext(&plain, &english(), &Config::default())]
+    );
+}
+
+#[test]
+fn test_justext_within_ignores_sibling_nav() {
+    let nav = "This navigation blurb is also written in plain English so that on its own it \
+               would be long enough and have enough stopwords to be kept by the classifier.";
+    let html = format!(
+        "<html><body><nav><p>{nav}</p></nav>\
+         <article><h2>Post title</h2><p>{GOOD}</p></article>\
+         </body></html>"
+    );
+    let full = justext(&html, &english(), &Config::default());
+    assert!(full.iter().any(|p| p.text == nav && !p.is_boilerplate()));
+
+    let paragraphs = justext_within(&html, "article", &english(), &Config::default()).unwrap();
+    let texts: Vec<&str> = paragraphs.iter().map(|p| p.text.as_str()).collect();
+    assert_eq!(texts, ["Post title", GOOD]);
+    assert_eq!(paragraphs[1].xpath, "/html[1]/body[1]/article[1]/p[1]");
+    assert_eq!(paragraphs[1].class_type, ClassType::Good);
+    assert_eq!(
+        &html[paragraphs[1].source_start..paragraphs[1].source_end],
+        GOOD
+    );
+
+    assert!(
+        justext_within(&html, "main", &english(), &Config::default())
+            .unwrap()
+            .is_empty()
+    );
+    assert!(matches!(
+        justext_within(&html, "article[", &english(), &Config::default()),
+        Err(JustextError::InvalidSelector(s)) if s == "article["
+    ));
+}
+
+#[test]
+fn test_justext_within_detects_title() {
+    let html = format!(
+        "<html><head><title>Post title | Blog</title></head><body>\
+         <aside><h2>Also on the blog</h2><p>{GOOD}</p></aside>\
+         <article><h1>Post title</h1><p>{GOOD}</p><h2>Background</h2><p>{GOOD}</p></article>\
+         </body></html>"
+    );
+    let config = Config::default().with_detect_title(true);
+    let paragraphs = justext_within(&html, "article", &english(), &config).unwrap();
+    let titles: Vec<&str> = paragraphs
+        .iter()
+        .filter(|p| p.is_probably_title)
+        .map(|p| p.text.as_str())
+        .collect();
+    assert_eq!(titles, ["Post title"]);
+}
+
+#[test]
+fn test_extract_sentences() {
+    let html = "<html><body><p><a>Home</a> | <a>About</a></p>\
+                <p>The value of pi is roughly 3.14 and it is the ratio of the circumference of \
+                a circle to its diameter. Dr. Smith said that it is one of the most important \
+                numbers in all of the history of mathematics. It is also irrational!</p>\
+                </body></html>";
+    let sentences = extract_sentences(html, &english(), &Config::default());
+    assert_eq!(
+        sentences,
+        [
+            "The value of pi is roughly 3.14 and it is the ratio of the circumference of \
+             a circle to its diameter.",
+            "Dr. Smith said that it is one of the most important numbers in all of the \
+             history of mathematics.",
+            "It is also irrational!",
+        ]
+    );
+}
+
+#[test]
+fn test_paragraph_merge_keeps_metrics_consistent() {
+    let mut first = Paragraph::builder()
+        .dom_path("html.body.table.tr.td")
+        .xpath("/html[1]/body[1]/table[1]/tr[1]/td[1]")
+        .text("Read the docs")
+        .chars_count_in_links(4)
+        .tags_count(1)
+        .build();
+    first.source_start = 10;
+    first.source_end = 30;
+    first.text_nodes = Some(vec!["Read the".to_string(), "docs".to_string()]);
+    let mut second = Paragraph::builder()
+        .text("and the changelog")
+        .chars_count_in_links(9)
+        .tags_count(2)
+        .build();
+    second.source_start = 35;
+    second.source_end = 60;
+    second.text_nodes = Some(vec!["and the changelog".to_string()]);
+
+    first.merge(&second);
+    assert_eq!(first.text, "Read the docs and the changelog");
+    assert_eq!(first.words_count, 6);
+    assert_eq!(first.chars_count_in_links, 13);
+    assert_eq!(first.tags_count, 3);
+    assert!((first.links_density() - 13.0 / 31.0).abs() < 1e-9);
+    assert_eq!(first.xpath, "/html[1]/body[1]/table[1]/tr[1]/td[1]");
+    assert_eq!((first.source_start, first.source_end), (10, 60));
+    assert_eq!(
+        first.text_nodes.as_deref(),
+        Some(&["Read the", "docs", "and the changelog"].map(String::from)[..])
+    );
+
+    let mut empty = Paragraph::builder().build();
+    empty.merge(&second);
+    assert_eq!(empty.text, "and the changelog");
+    assert_eq!((empty.source_start, empty.source_end), (35, 60));
+    assert_eq!(empty.text_nodes, second.text_nodes);
+}
+
+#[test]
+fn test_extract_if_changed() {
+    let page = format!("<html><body><p><a>Home</a></p><p>{GOOD}</p></body></html>");
+    let config = Config::default();
+
+    let (text, hash) = extract_if_changed(&page, "English", &config, 0)
+        .unwrap()
+        .unwrap();
+    assert_eq!(text, GOOD);
+    assert_eq!(hash, content_hash(&page, &english(), &config));
+
+    let renavigated = format!("<html><body><p><a>Sports</a></p><p>{GOOD}</p></body></html>");
+    assert_eq!(
+        extract_if_changed(&renavigated, "English", &config, hash).unwrap(),
+        None
+    );
+
+    let edited = format!("<html><body><p>{GOOD} Updated.</p></body></html>");
+    let (text, new_hash) = extract_if_changed(&edited, "English", &config, hash)
+        .unwrap()
+        .unwrap();
+    assert!(text.ends_with("Updated."));
+    assert_ne!(new_hash, hash);
+}
+
+#[test]
+fn test_reclassify_diff_reports_changed_indices() {
+    let html = format!(
+        "<html><body><p><a>Home</a> | About</p><p>{GOOD}</p><p>And that is the end of it.</p></body></html>"
+    );
+    let mut ps = justext(&html, &english(), &Config::default());
+    let before: Vec<ClassType> = ps.iter().map(|p| p.class_type).collect();
+    assert_eq!(before, [ClassType::Bad, ClassType::Good, ClassType::Bad]);
+
+    // Nothing changes when re-run with the same config.
+    assert!(reclassify_diff(&mut ps, &english(), &Config::default()).is_empty());
+
+    // A lower length_low lets the closing line qualify as NearGood next to good content.
+    let config = Config::default().with_length_low(10);
+    let changed = reclassify_diff(&mut ps, &english(), &config);
+    assert_eq!(ps, justext(&html, &english(), &config));
+    let expected: Vec<usize> = (0..ps.len())
+        .filter(|&i| ps[i].class_type != before[i])
+        .collect();
+    assert_eq!(changed, expected);
+    assert_eq!(changed, [2]);
+
+    // Reclassifying never rewrites text, so a later run starts from the same paragraphs.
+    let stripping = config
+        .clone()
+        .with_strip_trailing_phrases(vec!["and that is the end of it".to_string()]);
+    reclassify_diff(&mut ps, &english(), &stripping);
+    assert_eq!(ps[2].text, "And that is the end of it.");
+    reclassify_diff(&mut ps, &english(), &config);
+    assert_eq!(ps, justext(&html, &english(), &config));
+}
+
+#[test]
+fn test_drop_consent_banners() {
+    let banner = "We use cookies and similar technologies to give you the best experience on \
+                  our website, to analyse our traffic and to show you content that is relevant \
+                  to you. You can accept all of them or manage preferences at any time in the \
+                  settings, as described in our privacy policy.";
+    let html = format!("<html><body><div>{banner}</div><p>{GOOD}</p></body></html>");
+
+    let ps = justext(&html, &english(), &Config::default());
+    assert_eq!(ps[0].class_type, ClassType::Good);
+
+    let ps = justext(
+        &html,
+        &english(),
+        &Config::default().with_drop_consent_banners(true),
+    );
+    assert_eq!(ps[0].class_type, ClassType::Bad);
+    assert_eq!(ps[1].class_type, ClassType::Good);
+
+    let config = Config::default()
+        .with_drop_consent_banners(true)
+        .with_consent_phrases(vec!["NO SUCH PHRASE".to_string()]);
+    assert_eq!(
+        justext(&html, &english(), &config)[0].class_type,
+        ClassType::Good
+    );
+}
+
+#[test]
+fn test_extract_by_language_buckets() {
+    let french_text = "Ceci est un paragraphe qui contient beaucoup de mots vides de la langue \
+                       française et il devrait être classé comme un bon contenu par l'algorithme \
+                       parce que le texte est assez long pour dépasser le seuil de deux cents \
+                       caractères.";
+    let html =
+        format!("<html><body><p>{GOOD}</p><p>{french_text}</p><p>12345 67890</p></body></html>");
+
+    let buckets = extract_by_language(&html, &["English", "French"], &Config::default()).unwrap();
+    assert_eq!(buckets.len(), 2);
+    assert_eq!(buckets["English"], GOOD);
+    assert_eq!(buckets["French"], french_text);
+    assert!(!buckets.contains_key(UNKNOWN_LANGUAGE));
+
+    assert!(extract_by_language(&html, &["Klingon"], &Config::default()).is_err());
+}
+
+#[test]
+fn test_extract_above_fold() {
+    let html = format!(
+        "<html><body><ul><li><a href=\"/\">Home</a></li><li><a href=\"/news\">News</a></li></ul>\
+         <p>{GOOD}</p><p>{GOOD}</p></body></html>"
+    );
+
+    let preview = extract_above_fold(&html, &english(), &Config::default(), 40);
+    assert_eq!(preview, "This is a sentence that contains many");
+    assert!(preview.chars().count() <= 40);
+
+    let budget = GOOD.chars().count() + 1 + 12;
+    let preview = extract_above_fold(&html, &english(), &Config::default(), budget);
+    assert_eq!(preview, format!("{GOOD}\nThis is a"));
+
+    assert_eq!(
+        extract_above_fold(&html, &english(), &Config::default(), 0),
+        ""
+    );
+
+    // An over-long first word is cut rather than leaving the preview empty.
+    let word = "Supercalifragilisticexpialidocious";
+    let html = format!("<html><body><p>{word} {GOOD}</p></body></html>");
+    assert_eq!(
+        extract_above_fold(&html, &english(), &Config::default(), 20),
+        "Supercalifragilistic"
+    );
+
+    // Line breaks inside the kept text survive truncation.
+    let html = format!("<html><body><p>{GOOD}<br>Second line here</p></body></html>");
+    let config = Config::default().with_br_as_newline(true);
+    let budget = GOOD.chars().count() + 1 + 12;
+    assert_eq!(
+        extract_above_fold(&html, &english(), &config, budget),
+        format!("{GOOD}\nSecond line")
+    );
+}
+
+#[test]
+fn test_blocklist_patterns_drop_ad_container() {
+    let html = format!(
+        "<html><body><div class=\"ad-banner\"><p>{GOOD}</p></div><p>{GOOD}</p></body></html>"
+    );
+
+    let ps = justext(&html, &english(), &Config::default());
+    assert!(ps.iter().all(|p| p.class_type == ClassType::Good));
+
+    let config = Config::default().with_blocklist_patterns(vec!["AD-*".to_string()]);
+    let ps = justext(&html, &english(), &config);
+    assert_eq!(ps[0].class_type, ClassType::Bad);
+    assert_eq!(ps[0].reason, Some(justext::ClassReason::Blocklisted));
+    assert_eq!(ps[1].class_type, ClassType::Good);
+}
+
+#[test]
+fn test_extract_canonical() {
+    let html = "<html><body><ul><li><a href=\"/\">Home</a></li></ul>\
+                <p>  This is a sentence that contains many common English stopwords and it\r\n\
+                should be classified as good content by the algorithm because the text is \
+                long enough that it exceeds the length_high threshold of two hundred characters.\t</p>\
+                <p>And this is the second sentence that contains many common English stopwords \
+                and it should be classified as good content by the algorithm because it is \
+                long enough to exceed the   length_high threshold of two hundred characters.</p>\
+                </body></html>";
+
+    let canonical = extract_canonical(html, &english(), &Config::default());
+    assert_eq!(
+        canonical.as_bytes(),
+        b"This is a sentence that contains many common English stopwords and it should be \
+classified as good content by the algorithm because the text is long enough that it exceeds \
+the length_high threshold of two hundred characters.\n\
+And this is the second sentence that contains many common English stopwords and it should be \
+classified as good content by the algorithm because it is long enough to exceed the length_high \
+threshold of two hundred characters.\n"
+    );
+    assert_eq!(
+        extract_canonical("<html><body></body></html>", &english(), &Config::default()),
+        ""
+    );
+}
+
+#[test]
+fn test_extract_all() {
+    let article = format!("<html><body><p>{GOOD}</p></body></html>");
+    let nav = "<html><body><a href=\"/\">Home</a></body></html>";
+    let docs = [
+        ("a.html", article.as_str()),
+        ("b.html", nav),
+        ("c.html", article.as_str()),
+    ];
+
+    let results = extract_all(docs, "English", &Config::default()).unwrap();
+    assert_eq!(
+        results,
+        [
+            ("a.html".to_string(), GOOD.to_string()),
+            ("b.html".to_string(), String::new()),
+            ("c.html".to_string(), GOOD.to_string()),
+        ]
+    );
+
+    let mut processed = 0;
+    let lazy = docs.iter().map(|&d| {
+        processed += 1;
+        d
+    });
+    assert!(extract_all(lazy, "Klingon", &Config::default()).is_err());
+    assert_eq!(processed, 0);
+}
+
+#[test]
+fn test_boilerplate_path_histogram() {
+    let footer = "<footer><p><a href=\"/about\">About us</a></p>\
+                  <p><a href=\"/contact\">Contact</a></p></footer>";
+    let page = |body: &str| format!("<html><body>{body}{footer}</body></html>");
+    let docs = [
+        page("<aside><p><a href=\"/x\">Sidebar link</a></p></aside>"),
+        page(""),
+        page(""),
+    ];
+    let docs: Vec<&str> = docs.iter().map(String::as_str).collect();
+
+    let histogram = boilerplate_path_histogram(&docs, &english(), &Config::default());
+    // Two footer paragraphs per page still count once per page.
+    assert_eq!(histogram[0], ("html.body.footer.p".to_string(), 3));
+    assert_eq!(histogram[1], ("html.body.aside.p".to_string(), 1));
+    assert_eq!(histogram.len(), 2);
+}
+
+#[test]
+fn test_inline_removal_placeholder() {
+    let html = "<html><body><p>Press<button>OK</button>to<b>day</b>continue</p></body></html>";
+
+    let ps = justext(html, &english(), &Config::default());
+    assert_eq!(ps[0].text, "Presstodaycontinue");
+
+    let config = Config::default().with_inline_removal_placeholder(Some(' '));
+    let ps = justext(html, &english(), &config);
+    // Only the removed <button> leaves a trace; <b> is flattened as before.
+    assert_eq!(ps[0].text, "Press todaycontinue");
+}
+
+#[test]
+fn test_anchor_largest_good_rescues_buried_content() {
+    let links =
+        "<p><a href=\"/a\">Home</a> | <a href=\"/b\">News</a> | <a href=\"/c\">Sport</a></p>";
+    let html = format!("<html><body>{links}{links}<p>{GOOD}</p>{links}{links}</body></html>");
+    // A strict stopword threshold leaves the content NearGood; with only Bad
+    // neighbours, revision then drops it.
+    let config = Config::default().with_stopwords_high(0.9);
+
+    let ps = justext(&html, &english(), &config);
+    assert_eq!(ps[2].initial_class, ClassType::NearGood);
+    assert_eq!(ps[2].class_type, ClassType::Bad);
+
+    let ps = justext(&html, &english(), &config.with_anchor_largest_good(true));
+    let good: Vec<&str> = ps
+        .iter()
+        .filter(|p| !p.is_boilerplate())
+        .map(|p| p.text.as_str())
+        .collect();
+    assert_eq!(good, [GOOD]);
+}
+
+#[test]
+fn test_document_score_ranks_content_over_boilerplate() {
+    let nav = "<ul><li><a href=\"/\">Home</a></li><li><a href=\"/news\">News</a></li>\
+               <li><a href=\"/contact\">Contact</a></li></ul>";
+    let article = format!(
+        "<html><body>{nav}{}</body></html>",
+        format!("<p>{GOOD}</p>").repeat(20)
+    );
+    let poor = format!("<html><body>{nav}<p>{GOOD}</p>{nav}{nav}</body></html>");
+    let empty = format!("<html><body>{nav}</body></html>");
+
+    let score = |html: &str| document_score(&justext(html, &english(), &Config::default()));
+    let (rich, poor, empty) = (score(&article), score(&poor), score(&empty));
+    assert!(rich > 0.9, "rich = {rich}");
+    assert!(poor < rich, "poor = {poor}");
+    assert_eq!(empty, 0.0);
+}
+
+#[test]
+fn test_extract_markdown_keeps_heading_levels() {
+    let second = "And this is another sentence that contains many common English stopwords, \
+                  so it should also be classified as good content by the algorithm because it \
+                  is long enough to exceed the length_high threshold of two hundred characters.";
+    let html = format!(
+        "<html><body><h2>Why boilerplate matters</h2><p>{GOOD}</p><p>{second}</p></body></html>"
+    );
+
+    let markdown = extract_markdown(&html, &english(), &Config::default());
+    assert_eq!(
+        markdown,
+        format!("## Why boilerplate matters\n\n{GOOD}\n\n{second}")
+    );
+}
+
+#[test]
+fn test_extract_markdown_renders_data_table() {
+    let html = format!(
+        "<html><body><p>{GOOD}</p><table>\
+         <thead><tr><th>Name</th><th>Age</th></tr></thead>\
+         <tbody><tr><td>Alice</td><td>30</td></tr><tr><td>Bob | Jr.</td><td>41</td></tr></tbody>\
+         </table><p>{GOOD}</p></body></html>"
+    );
+
+    let markdown = extract_markdown(&html, &english(), &Config::default());
+    assert_eq!(
+        markdown,
+        format!(
+            "{GOOD}\n\n| Name | Age |\n| --- | --- |\n| Alice | 30 |\n| Bob \\| Jr. | 41 |\n\n{GOOD}"
+        )
+    );
+}
+
+#[test]
+fn test_extract_markdown_headerless_table() {
+    let html = format!(
+        "<html><body><p>{GOOD}</p><table><tr><td>Alice</td><td>30</td></tr>\
+         <tr><td>Bob</td><td>41</td></tr></table><p>{GOOD}</p></body></html>"
+    );
+
+    let markdown = extract_markdown(&html, &english(), &Config::default());
+    assert_eq!(
+        markdown,
+        format!("{GOOD}\n\n|  |  |\n| --- | --- |\n| Alice | 30 |\n| Bob | 41 |\n\n{GOOD}")
+    );
+
+    let config = Config::default().with_markdown_skip_headerless_tables(true);
+    let markdown = extract_markdown(&html, &english(), &config);
+    assert_eq!(
+        markdown,
+        format!("{GOOD}\n\nAlice\n\n30\n\nBob\n\n41\n\n{GOOD}")
+    );
+}
+
+#[test]
+fn test_extract_sorted_by_xpath_compares_ordinals_numerically() {
+    let html = format!(
+        "<html><body>{}</body></html>",
+        (1..=11)
+            .map(|i| format!("<div><p>{i}. {GOOD}</p></div>"))
+            .collect::<String>()
+    );
+
+    let ps = extract_sorted_by_xpath(&html, &english(), &Config::default());
+    let xpaths: Vec<&str> = ps.iter().map(|p| p.xpath.as_str()).collect();
+    // Plain string sorting would put div[10] and div[11] before div[2].
+    let expected: Vec<String> = (1..=11)
+        .map(|i| format!("/html[1]/body[1]/div[{i}]/p[1]"))
+        .collect();
+    assert_eq!(xpaths, expected);
+}
+
+#[test]
+fn test_extract_sections() {
+    let body = "And this is another sentence that contains many common English stopwords, \
+                so it should also be classified as good content by the algorithm because it \
+                is long enough to exceed the length_high threshold of two hundred characters.";
+    let html = format!(
+        "<html><body><p>{GOOD}</p><h2>Background</h2><p>{body}</p><p>{GOOD}</p>\
+         <h3>Details</h3><p>{body}</p></body></html>"
+    );
+
+    let sections = extract_sections(&html, "English", &Config::default()).unwrap();
+    let summary: Vec<(Option<&str>, u8, usize)> = sections
+        .iter()
+        .map(|s: &Section| (s.heading.as_deref(), s.level, s.paragraphs.len()))
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            (None, 0, 1),
+            (Some("Background"), 2, 2),
+            (Some("Details"), 3, 1)
+        ]
+    );
+    assert_eq!(sections[1].paragraphs[0], body);
+}
+
+#[test]
+fn test_source_offsets() {
+    let html = format!(
+        "<html><head><title>Home</title></head><body>\n\
+         <p><a href=\"/\">Home</a></p>\n\
+         <p>\n  {GOOD}\n</p>\n\
+         <p>Caf&eacute; &amp; bar, <em>open</em>\n late</p>\n\
+         </body></html>"
+    );
+
+    let ps = justext(&html, &english(), &Config::default());
+    assert_eq!(&html[ps[0].source_start..ps[0].source_end], "Home");
+    assert_eq!(&html[ps[1].source_start..ps[1].source_end], GOOD);
+    // Spans several text nodes and the markup between them; entities stay as written.
+    assert_eq!(
+        &html[ps[2].source_start..ps[2].source_end],
+        "Caf&eacute; &amp; bar, <em>open</em>\n late"
+    );
+}
+
+#[test]
+fn test_source_offsets_without_head_end_tag() {
+    // `</head>` is optional; the body must still be located.
+    let html = "<html><head><title>T</title><body><p>First line</p><p>Second line</p>";
+    let ps = justext(html, &english(), &Config::default());
+    assert_eq!(&html[ps[0].source_start..ps[0].source_end], "First line");
+    assert_eq!(&html[ps[1].source_start..ps[1].source_end], "Second line");
+}
+
+#[test]
+fn test_source_offsets_stay_linear_on_large_pages() {
+    // With `preserve_scripts` no paragraph occurs verbatim in the source; an unbounded
+    // search per paragraph made this page take minutes.
+    let html: String = (0..8000)
+        .map(|i| {
+            format!(
+                "<p>Paragraph {i} with a footnote<sup>{i}</sup> and enough words to pad                  it out a little further.</p>\n"
+            )
+        })
+        .collect();
+    let config = Config::default().with_preserve_scripts(true);
+    let start = std::time::Instant::now();
+    let ps = justext(&html, &english(), &config);
+    assert_eq!(ps.len(), 8000);
+    assert!(
+        start.elapsed() < std::time::Duration::from_secs(10),
+        "took {:?}",
+        start.elapsed()
+    );
+}
+
+#[test]
+fn test_drop_interactive() {
+    let html = format!(
+        "<html><body><div onclick=\"track('promo')\"><p>{GOOD}</p></div><p>{GOOD}</p></body></html>"
+    );
+
+    let ps = justext(&html, &english(), &Config::default());
+    assert!(ps[0].interactive);
+    assert!(!ps[1].interactive);
+    assert!(ps.iter().all(|p| p.class_type == ClassType::Good));
+
+    let ps = justext(
+        &html,
+        &english(),
+        &Config::default().with_drop_interactive(true),
+    );
+    assert_eq!(ps[0].class_type, ClassType::Bad);
+    assert_eq!(ps[0].reason, Some(justext::ClassReason::Interactive));
+    assert_eq!(ps[1].class_type, ClassType::Good);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_justext_batch_matches_sequential() {
+    let docs: Vec<String> = (0..8)
+        .map(|i| {
+            let nav = "<ul><li><a href=\"/\">Home</a></li></ul>".repeat(i % 3);
+            format!("<html><body>{nav}<h1>Doc {i}</h1><p>{GOOD}</p></body></html>")
+        })
+        .collect();
+    let htmls: Vec<&str> = docs.iter().map(String::as_str).collect();
+    let config = Config::default();
+    let stoplist = english();
+
+    let sequential: Vec<Vec<Paragraph>> = htmls
+        .iter()
+        .map(|html| justext(html, &stoplist, &config))
+        .collect();
+    assert_eq!(
+        justext::justext_batch(&htmls, &stoplist, &config),
+        sequential
+    );
+}
+
+#[test]
+fn test_annotate_html_marks_paragraph_elements() {
+    let html = format!(
+        "<html><head><title>T</title></head><body>\
+         <ul><li><a href=\"/\">Home</a></li></ul>\
+         <div><p class=\"lead\">{GOOD}</p></div>\
+         <script>track()</script></body></html>"
+    );
+    let annotated = annotate_html(&html, &english(), &Config::default());
+    assert!(annotated.contains(&format!(
+        "<p class=\"lead\" data-justext-class=\"good\">{GOOD}</p>"
+    )));
+    assert!(annotated.contains("<li data-justext-class=\"bad\"><a href=\"/\">Home</a></li>"));
+    assert!(annotated.contains("<div>"));
+    assert!(!annotated.contains("track()"));
+    assert!(!annotated.contains("<title>"));
+}
+
+#[test]
+fn test_detect_title_flags_lead_heading() {
+    let html = format!(
+        "<html><head><title>The River Rises | Valley Times</title></head><body>\
+         <h1>The river rises</h1><p>{GOOD}</p><h2>What happens next</h2><p>{GOOD}</p>\
+         </body></html>"
+    );
+    let ps = justext(&html, &english(), &Config::default());
+    assert!(ps.iter().all(|p| !p.is_probably_title));
+
+    let ps = justext(
+        &html,
+        &english(),
+        &Config::default().with_detect_title(true),
+    );
+    let titles: Vec<&str> = ps
+        .iter()
+        .filter(|p| p.is_probably_title)
+        .map(|p| p.text.as_str())
+        .collect();
+    assert_eq!(titles, ["The river rises"]);
+    assert!(ps
+        .iter()
+        .any(|p| p.heading && p.text == "What happens next"));
+}
+
+#[test]
+fn test_detect_title_per_article() {
+    let html = format!(
+        "<html><head><title>Second post | Blog</title></head><body>\
+         <article><h2>First post</h2><p>{GOOD}</p></article>\
+         <article><h2>Second post</h2><p>{GOOD}</p></article>\
+         </body></html>"
+    );
+    let config = Config::default().with_detect_title(true);
+    let groups = classify_articles(&html, &english(), &config);
+    let titles: Vec<Vec<&str>> = groups
+        .iter()
+        .map(|g| {
+            g.iter()
+                .filter(|p| p.is_probably_title)
+                .map(|p| p.text.as_str())
+                .collect()
+        })
+        .collect();
+    assert_eq!(titles, [["First post"], ["Second post"]]);
+}
+
+#[cfg(feature = "encoding")]
+#[test]
+fn test_extract_text_from_bytes_windows_1252() {
+    let mut bytes = b"<html><head><meta charset=\"windows-1252\"></head><body><p>\x93Quoted\x94 \
+                      \x96 "
+        .to_vec();
+    bytes.extend_from_slice(GOOD.as_bytes());
+    bytes.extend_from_slice(b"</p></body></html>");
+
+    let text = justext::extract_text_from_bytes(&bytes, "English", &Config::default()).unwrap();
+    assert_eq!(text, format!("\u{201C}Quoted\u{201D} \u{2013} {GOOD}"));
+}
@@ -0,0 +1,65 @@
+// Classification logging through the `log` crate (requires the `log` feature).
+#![cfg(feature = "log")]
+
+use std::sync::Mutex;
+
+use justext::{get_stoplist, justext, Config};
+use log::{Level, LevelFilter, Log, Metadata, Record};
+
+struct CaptureLogger {
+    records: Mutex<Vec<(Level, String)>>,
+}
+
+impl Log for CaptureLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.target().starts_with("justext")
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            self.records
+                .lock()
+                .unwrap()
+                .push((record.level(), record.args().to_string()));
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: CaptureLogger = CaptureLogger {
+    records: Mutex::new(Vec::new()),
+};
+
+#[test]
+fn test_classification_decisions_are_logged() {
+    log::set_logger(&LOGGER).unwrap();
+    log::set_max_level(LevelFilter::Trace);
+
+    let content = "This paragraph contains many common English stopwords and it is long \
+                   enough to be classified as good content with the English stoplist applied \
+                   correctly by the justext algorithm when processing this article text here.";
+    let html = format!(
+        "<html><body><h1>Title</h1><p>{content}</p><p><a>Home</a> | <a>About</a></p></body></html>"
+    );
+    justext(&html, &get_stoplist("English").unwrap(), &Config::default());
+
+    let records = LOGGER.records.lock().unwrap();
+    let has = |level: Level, needle: &str| {
+        records
+            .iter()
+            .any(|(l, msg)| *l == level && msg.contains(needle))
+    };
+    assert!(has(Level::Debug, "classify /html[1]/body[1]/p[1]: Good"));
+    assert!(has(Level::Debug, "LinkDensityTooHigh"));
+    assert!(has(Level::Debug, "stopword_density="));
+    assert!(has(
+        Level::Trace,
+        "revise stage 2 /html[1]/body[1]/h1[1]: neighbours prev=Bad next=Good"
+    ));
+    assert!(has(Level::Debug, "revise stage 4 /html[1]/body[1]/h1[1]"));
+    assert!(has(
+        Level::Debug,
+        "revise /html[1]/body[1]/h1[1]: Short -> Good"
+    ));
+}
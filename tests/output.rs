@@ -0,0 +1,132 @@
+// Output format tests: NDJSON, CSV, and friends.
+
+use justext::{
+    extract_html, extract_html_with_ranges, get_stoplist, justext, to_csv, to_debug_html,
+    to_ndjson, Config,
+};
+
+const GOOD: &str = "This is a sentence that contains many common English stopwords and it \
+                    should be classified as good content by the algorithm because the text is \
+                    long enough that it exceeds the length_high threshold of two hundred characters.";
+
+fn english() -> std::collections::HashSet<String> {
+    get_stoplist("English").unwrap()
+}
+
+#[test]
+fn test_ndjson_all_paragraphs_include_class() {
+    let html = format!("<html><body><p><a>Home</a> | <a>About</a></p><p>{GOOD}</p></body></html>");
+    let ps = justext(&html, &english(), &Config::default());
+    let ndjson = to_ndjson(&ps, false);
+    let lines: Vec<&str> = ndjson.lines().collect();
+    assert_eq!(
+        lines,
+        vec![
+            r#"{"text":"Home | About","xpath":"/html[1]/body[1]/p[1]","word_count":3,"class":"bad"}"#
+                .to_string(),
+            format!(
+                r#"{{"text":"{GOOD}","xpath":"/html[1]/body[1]/p[2]","word_count":37,"class":"good"}}"#
+            ),
+        ]
+    );
+    assert!(ndjson.ends_with('\n'));
+}
+
+#[test]
+fn test_ndjson_good_only() {
+    let html = format!("<html><body><p><a>Home</a> | <a>About</a></p><p>{GOOD}</p></body></html>");
+    let ps = justext(&html, &english(), &Config::default());
+    let ndjson = to_ndjson(&ps, true);
+    assert_eq!(
+        ndjson,
+        format!("{{\"text\":\"{GOOD}\",\"xpath\":\"/html[1]/body[1]/p[2]\",\"word_count\":37}}\n")
+    );
+}
+
+#[test]
+fn test_ndjson_escapes_newlines_in_text() {
+    let html = "<html><body><p>line one\nline two</p></body></html>";
+    let ps = justext(html, &english(), &Config::default());
+    let ndjson = to_ndjson(&ps, false);
+    assert_eq!(ndjson.lines().count(), 1);
+    assert!(ndjson.contains(r#""text":"line one\nline two""#));
+}
+
+#[test]
+fn test_csv_quotes_commas_and_newlines() {
+    let html = format!(
+        "<html><body><h1>Title</h1><p>Hello, \"world\"\nnext line</p><p>{GOOD}</p></body></html>"
+    );
+    let ps = justext(&html, &english(), &Config::default());
+    let csv = to_csv(&ps);
+    assert_eq!(
+        csv,
+        format!(
+            "dom_path,xpath,word_count,link_char_count,tag_count,class_type,heading,text\n\
+             html.body.h1,/html[1]/body[1]/h1[1],1,0,0,{},true,Title\n\
+             html.body.p,/html[1]/body[1]/p[1],4,0,0,{},false,\"Hello, \"\"world\"\"\nnext line\"\n\
+             html.body.p,/html[1]/body[1]/p[2],37,0,0,good,false,{GOOD}\n",
+            ps[0].class_type.as_str(),
+            ps[1].class_type.as_str(),
+        )
+    );
+}
+
+#[test]
+fn test_extract_html_with_ranges_slices_each_paragraph() {
+    let html = format!(
+        "<html><body><p><a>Home</a> | <a>About</a></p><h2>Caf\u{00E9} &amp; more</h2>\
+         <p>{GOOD}</p><p>{GOOD} \u{2014} again</p></body></html>"
+    );
+    let (out, ranges) = extract_html_with_ranges(&html, &english(), &Config::default());
+    let pieces: Vec<&str> = ranges.iter().map(|r| &out[r.clone()]).collect();
+    assert_eq!(
+        pieces,
+        [
+            "<h2>Caf\u{00E9} &amp; more</h2>".to_string(),
+            format!("<p>{GOOD}</p>"),
+            format!("<p>{GOOD} \u{2014} again</p>"),
+        ]
+    );
+    assert_eq!(out, pieces.join("\n") + "\n");
+}
+
+#[test]
+fn test_extract_html_wraps_headings_and_paragraphs() {
+    let html = format!(
+        "<html><body><p><a>Home</a> | <a>About</a></p><h3>Fish &amp; chips</h3>\
+         <div><p>{GOOD} <b>Bold</b> &lt;tag&gt;</p></div></body></html>"
+    );
+    let out = extract_html(&html, &english(), &Config::default());
+    assert_eq!(
+        out,
+        format!("<h3>Fish &amp; chips</h3>\n<p>{GOOD} Bold &lt;tag&gt;</p>\n")
+    );
+}
+
+#[test]
+fn test_debug_html_marks_every_class() {
+    let html = format!(
+        "<html><body><p>{GOOD}</p>\
+         <p>Some other words that are long enough to be classified near good stuff.</p>\
+         <p><a>Home</a> | <a>About &amp; more</a></p><p>Tiny</p></body></html>"
+    );
+    // Without revision, Short and NearGood paragraphs keep their initial class.
+    let config = Config::default().with_skip_revision(true);
+    let paragraphs = justext(&html, &english(), &config);
+    let out = to_debug_html(&paragraphs);
+    assert!(out.starts_with("<!DOCTYPE html>"));
+    assert!(out.contains("<style>"));
+    for class in ["good", "bad", "short", "neargood"] {
+        assert!(
+            out.contains(&format!("<p class=\"{class}\"")),
+            "missing {class}"
+        );
+    }
+    assert!(out.contains(&format!(
+        "<p class=\"good\" data-xpath=\"/html[1]/body[1]/p[1]\">{GOOD}</p>"
+    )));
+    assert!(out.contains(
+        "<p class=\"bad\" data-xpath=\"/html[1]/body[1]/p[3]\">Home | About &amp; more</p>"
+    ));
+}
@@ -0,0 +1,102 @@
+// Serde round-trips for the classified-paragraph output.
+#![cfg(feature = "serde")]
+
+use justext::{
+    get_stoplist, justext, ClassReason, ClassType, Config, ConfigError, DistanceUnit, Paragraph,
+};
+
+#[test]
+fn test_class_type_serializes_lowercase() {
+    let names: Vec<String> = [
+        ClassType::Good,
+        ClassType::Bad,
+        ClassType::Short,
+        ClassType::NearGood,
+    ]
+    .iter()
+    .map(|c| serde_json::to_string(c).unwrap())
+    .collect();
+    assert_eq!(names, ["\"good\"", "\"bad\"", "\"short\"", "\"neargood\""]);
+    assert_eq!(
+        serde_json::to_string(&ClassReason::LinkDensityTooHigh).unwrap(),
+        "\"link_density_too_high\""
+    );
+}
+
+#[test]
+fn test_paragraphs_round_trip() {
+    let html = "<html><body>\
+                <h1>A heading</h1>\
+                <p>This is a sentence that contains many common English stopwords and it \
+                should be classified as good content by the algorithm because the text is \
+                long enough that it exceeds the length_high threshold of two hundred characters.</p>\
+                <p><a href=\"/\">Home</a> | <a href=\"/about\">About \"us\"</a></p>\
+                </body></html>";
+    let paragraphs = justext(html, &get_stoplist("English").unwrap(), &Config::default());
+
+    let json = serde_json::to_string(&paragraphs).unwrap();
+    let parsed: Vec<Paragraph> = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, paragraphs);
+    assert!(json.contains("\"class_type\":\"good\""));
+}
+
+#[test]
+fn test_config_from_json_partial() {
+    let config = Config::from_json(r#"{"length_low": 50}"#).unwrap();
+    let default = Config::default();
+    assert_eq!(config.length_low, 50);
+    assert_eq!(config.length_high, default.length_high);
+    assert_eq!(config.stopwords_low, default.stopwords_low);
+    assert_eq!(config.stopwords_high, default.stopwords_high);
+    assert_eq!(config.max_link_density, default.max_link_density);
+    assert_eq!(config.consent_phrases, default.consent_phrases);
+    assert_eq!(config.copyright_markers, default.copyright_markers);
+    assert_eq!(
+        config.drop_copyright_paragraphs,
+        default.drop_copyright_paragraphs
+    );
+}
+
+#[test]
+fn test_config_from_json_round_trip_and_errors() {
+    let config = Config::default()
+        .with_length_low(40)
+        .with_heading_distance_unit(DistanceUnit::Paragraphs)
+        .with_smoothing_window(Some(2))
+        .with_inline_removal_placeholder(Some('\u{fffc}'));
+    let json = serde_json::to_string(&config).unwrap();
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(value["heading_distance_unit"], "paragraphs");
+    let loaded = Config::from_json(&json).unwrap();
+    assert_eq!(loaded.length_low, 40);
+    assert_eq!(loaded.heading_distance_unit, DistanceUnit::Paragraphs);
+    assert_eq!(loaded.smoothing_window, Some(2));
+    assert_eq!(loaded.inline_removal_placeholder, Some('\u{fffc}'));
+
+    assert!(matches!(
+        Config::from_json(r#"{"length_lo": 50}"#),
+        Err(ConfigError::Json(_))
+    ));
+    assert!(matches!(
+        Config::from_json("not json"),
+        Err(ConfigError::Json(_))
+    ));
+    assert_eq!(
+        Config::from_json(r#"{"length_low": 500}"#).unwrap_err(),
+        ConfigError::LengthRange {
+            low: 500,
+            high: 200
+        }
+    );
+    assert!(matches!(
+        Config::from_json(r#"{"max_link_density": 2.0}"#),
+        Err(ConfigError::OutOfRange {
+            field: "max_link_density",
+            ..
+        })
+    ));
+    assert_eq!(
+        Config::from_json(r#"{"position_edge_fraction": 7}"#).unwrap_err(),
+        ConfigError::EdgeFraction { value: 7.0 }
+    );
+}
@@ -1,6 +1,12 @@
 // Port of test_utils.py — stoplist tests
 
-use justext::{available_languages, get_all_stoplists, get_stoplist};
+use std::collections::HashSet;
+
+use justext::{
+    available_languages, detect_language, extract_text_auto, extract_text_lang,
+    extract_text_language, get_all_stoplists, get_merged_stoplist, get_stoplist, get_stoplist_set,
+    justext_lang, justext_language, Config, JustextError, Language,
+};
 
 #[test]
 fn test_available_languages_count() {
@@ -14,6 +20,88 @@ fn test_available_languages_contains_english() {
     assert!(languages.contains(&"English"));
 }
 
+#[test]
+fn test_every_language_variant_has_a_stoplist() {
+    assert_eq!(
+        Language::ALL
+            .iter()
+            .map(Language::as_str)
+            .collect::<Vec<_>>(),
+        available_languages()
+    );
+    for &language in Language::ALL {
+        assert!(
+            !get_stoplist(language.as_str()).unwrap().is_empty(),
+            "{language}"
+        );
+        assert_eq!(language.as_str().parse::<Language>().unwrap(), language);
+    }
+    assert_eq!("ENGLISH".parse::<Language>().unwrap(), Language::English);
+    assert!(matches!(
+        "Klingon".parse::<Language>(),
+        Err(JustextError::UnknownLanguage(l)) if l == "Klingon"
+    ));
+}
+
+#[test]
+fn test_language_functions_match_string_versions() {
+    let html = "<html><body><p>This is a sentence that contains many common English stopwords and \
+                it should be classified as good content by the algorithm because the text is long \
+                enough that it exceeds the length_high threshold of two hundred characters.</p>\
+                <p><a href=\"/\">Home</a></p></body></html>";
+    let config = Config::default();
+    assert_eq!(
+        justext_language(html, Language::English, &config),
+        justext_lang(html, "English", &config).unwrap()
+    );
+    assert_eq!(
+        extract_text_language(html, Language::English, &config),
+        extract_text_lang(html, "English", &config).unwrap()
+    );
+}
+
+/// The original runtime parsing: one word per line, trimmed and lowercased, blank lines
+/// skipped.
+fn parse_stoplist(contents: &str) -> HashSet<String> {
+    contents
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .map(|word| word.to_lowercase())
+        .collect()
+}
+
+#[test]
+fn test_compiled_stoplists_match_parsed_files() {
+    let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("src/stoplists");
+    for language in available_languages() {
+        let contents = std::fs::read_to_string(dir.join(format!("{language}.txt"))).unwrap();
+        let parsed = parse_stoplist(&contents);
+        let set = get_stoplist_set(language).unwrap();
+        assert_eq!(set.len(), parsed.len(), "{language}");
+        assert!(set.iter().all(|word| parsed.contains(*word)), "{language}");
+        assert_eq!(get_stoplist(language).unwrap(), parsed, "{language}");
+    }
+    assert!(get_stoplist_set("english").unwrap().contains("the"));
+    assert!(get_stoplist_set("Klingon").is_none());
+}
+
+#[test]
+fn test_get_merged_stoplist() {
+    let merged = get_merged_stoplist(&["English", "spanish"]).unwrap();
+    assert!(merged.contains("the"));
+    assert!(merged.contains("el"));
+    let english = get_stoplist("English").unwrap();
+    assert!(merged.len() > english.len());
+    assert!(merged.len() < get_all_stoplists().len());
+
+    assert!(get_merged_stoplist(&[]).unwrap().is_empty());
+    assert!(matches!(
+        get_merged_stoplist(&["English", "Klingon", "Elvish"]),
+        Err(JustextError::UnknownLanguage(l)) if l == "Klingon"
+    ));
+}
+
 #[test]
 fn test_get_stoplist_english() {
     let stoplist = get_stoplist("English").unwrap();
@@ -174,3 +262,47 @@ fn test_all_100_languages() {
         assert!(languages.contains(lang), "missing language: {lang}");
     }
 }
+
+#[test]
+fn test_detect_language() {
+    let french = "<html><body><p>Le chat est sur la table et il regarde les oiseaux qui \
+                  chantent dans le jardin. Nous avons mang\u{e9} une pomme avec nos amis \
+                  pendant que la pluie tombait sur la ville.</p></body></html>";
+    let german = "<html><body><p>Der Hund l\u{e4}uft schnell \u{fc}ber die Stra\u{df}e, \
+                  weil er den Ball sehen will. Wir haben gestern mit unseren Freunden im \
+                  Garten gegessen, obwohl es sehr kalt war.</p></body></html>";
+    assert_eq!(detect_language(french), Some("French"));
+    assert_eq!(detect_language(german), Some("German"));
+    assert_eq!(
+        detect_language("<html><body><p>12345</p></body></html>"),
+        None
+    );
+    assert_eq!(detect_language(""), None);
+}
+
+#[test]
+fn test_extract_text_auto() {
+    let text = "Le chat est sur la table et il regarde les oiseaux qui chantent dans le \
+                jardin, et nous avons mang\u{e9} une pomme avec nos amis pendant que la pluie \
+                tombait sur la ville o\u{f9} nous habitons depuis de tr\u{e8}s longues ann\u{e9}es.";
+    let html = format!("<html><body><p>{text}</p></body></html>");
+    assert_eq!(extract_text_auto(&html, &Config::default()), text);
+    // No detectable language: falls back to the merged stoplist rather than failing.
+    assert_eq!(
+        extract_text_auto("<html><body></body></html>", &Config::default()),
+        ""
+    );
+}
+
+#[cfg(feature = "rust-stemmers")]
+#[test]
+fn test_stem_for_matching_uses_the_language_name() {
+    use justext::StemMatching;
+
+    let html = "<html><body><p>Others takes ways</p></body></html>";
+    let density =
+        |config: &Config| justext_lang(html, "English", config).unwrap()[0].stopword_density;
+    assert_eq!(density(&Config::default()), 0.0);
+    let stemmed = Config::default().with_stem_for_matching(StemMatching::Tokens);
+    assert_eq!(density(&stemmed), 1.0);
+}
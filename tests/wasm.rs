@@ -0,0 +1,20 @@
+// wasm-bindgen entry point, run in a JavaScript host with `wasm-pack test --node -- --features wasm`.
+#![cfg(all(feature = "wasm", target_arch = "wasm32"))]
+
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use justext::classify_paragraphs_json;
+
+#[wasm_bindgen_test]
+fn test_classify_paragraphs_json() {
+    let html = "<html><body><h1>Title</h1><p>Hello world</p></body></html>";
+    let json = classify_paragraphs_json(html, "English", "{\"no_headings\": true}").unwrap();
+    assert!(json.starts_with("[{"));
+    assert!(json.contains("\"text\":\"Title\""));
+    assert!(json.contains("\"heading\":false"));
+}
+
+#[wasm_bindgen_test]
+fn test_classify_paragraphs_json_unknown_language() {
+    assert!(classify_paragraphs_json("<p>Hello</p>", "Klingon", "").is_err());
+}
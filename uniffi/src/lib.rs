@@ -1,10 +1,15 @@
 uniffi::setup_scaffolding!();
 
-/// Errors returned by functions that take a language name.
+/// Errors returned by functions that take a language name or a CSS selector.
 #[derive(Debug, thiserror::Error, uniffi::Error)]
 pub enum JustextError {
     #[error("{reason}")]
     UnknownLanguage { reason: String },
+    #[error("{reason}")]
+    InvalidSelector { reason: String },
+    /// An error kind added to the core crate after these bindings.
+    #[error("{reason}")]
+    Other { reason: String },
 }
 
 impl From<justext::JustextError> for JustextError {
@@ -13,16 +18,29 @@ impl From<justext::JustextError> for JustextError {
             justext::JustextError::UnknownLanguage(lang) => JustextError::UnknownLanguage {
                 reason: format!("unknown language: {lang}"),
             },
+            justext::JustextError::InvalidSelector(selector) => JustextError::InvalidSelector {
+                reason: format!("invalid CSS selector: {selector}"),
+            },
+            e => JustextError::Other {
+                reason: e.to_string(),
+            },
         }
     }
 }
 
 /// Classification label for a paragraph.
+///
+/// Each variant has a stable integer code (see [`class_type_code`]) for storage:
+/// Good = 0, Bad = 1, Short = 2, NearGood = 3. Codes never change between releases.
 #[derive(uniffi::Enum)]
 pub enum ClassType {
+    /// Code 0.
     Good,
+    /// Code 1.
     Bad,
+    /// Code 2.
     Short,
+    /// Code 3.
     NearGood,
 }
 
@@ -47,6 +65,21 @@ pub struct Paragraph {
     pub initial_class: ClassType,
     /// Whether this paragraph is a heading.
     pub heading: bool,
+    /// Stopword density the classifier compared against the thresholds.
+    pub stopword_density: f64,
+    /// Link density the classifier compared against `max_link_density`.
+    pub link_density: f64,
+}
+
+/// Good content under one heading, as returned by [`extract_sections`].
+#[derive(uniffi::Record)]
+pub struct Section {
+    /// Heading text; `None` for content before the first heading.
+    pub heading: Option<String>,
+    /// Heading level (2 for `<h2>`); 0 when there is no heading.
+    pub level: i64,
+    /// Good paragraph texts under the heading.
+    pub paragraphs: Vec<String>,
 }
 
 /// Configuration for the JusText algorithm.
@@ -76,6 +109,20 @@ pub fn default_config() -> Config {
     }
 }
 
+/// Stable integer code for a class type: Good = 0, Bad = 1, Short = 2, NearGood = 3.
+///
+/// Unlike the binding languages' own enum ordinals, these values are part of the API
+/// and will not change across releases, so they are safe to persist.
+#[uniffi::export]
+pub fn class_type_code(ct: ClassType) -> i32 {
+    match ct {
+        ClassType::Good => 0,
+        ClassType::Bad => 1,
+        ClassType::Short => 2,
+        ClassType::NearGood => 3,
+    }
+}
+
 /// Returns the list of available language names.
 #[uniffi::export]
 pub fn available_languages() -> Vec<String> {
@@ -124,6 +171,20 @@ pub fn classify_paragraphs_with(
     Ok(paragraphs.into_iter().map(to_ffi_paragraph).collect())
 }
 
+/// Extract good content grouped into sections at each heading, with default config.
+#[uniffi::export]
+pub fn extract_sections(html: String, language: String) -> Result<Vec<Section>, JustextError> {
+    let sections = justext::extract_sections(&html, &language, &justext::Config::default())?;
+    Ok(sections
+        .into_iter()
+        .map(|s| Section {
+            heading: s.heading,
+            level: i64::from(s.level),
+            paragraphs: s.paragraphs,
+        })
+        .collect())
+}
+
 // --- Internal conversion helpers ---
 
 fn to_core_config(c: &Config) -> justext::Config {
@@ -157,5 +218,7 @@ fn to_ffi_paragraph(p: justext::Paragraph) -> Paragraph {
         class_type: convert_class_type(p.class_type),
         initial_class: convert_class_type(p.initial_class),
         heading: p.heading,
+        stopword_density: p.stopword_density,
+        link_density: p.link_density,
     }
 }